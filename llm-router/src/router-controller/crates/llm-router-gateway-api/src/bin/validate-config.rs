@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validates a router config without starting the gateway
+use clap::{Parser, ValueEnum};
+use llm_router_gateway_api::config::{ConfigFormat, RouterConfig};
+use llm_router_gateway_api::error::ConfigError;
+use serde_json::json;
+use std::io::Read;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Path to the config file to validate, or `-` to read YAML or JSON from
+    /// stdin
+    path: String,
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+    /// Fail on unresolved environment variables instead of warning
+    #[arg(long)]
+    strict: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let mut warnings = Vec::new();
+    let result = if args.path == "-" {
+        // Stdin can only be read once, so both attempts parse the same buffer
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .map_err(ConfigError::from)
+            .and_then(|_| {
+                match RouterConfig::load_from_reader(content.as_bytes(), ConfigFormat::Yaml) {
+                    Err(ConfigError::UnresolvedEnvVars { vars }) if !args.strict => {
+                        warnings.extend(unset_var_warnings(&vars));
+                        RouterConfig::load_from_reader_lenient(
+                            content.as_bytes(),
+                            ConfigFormat::Yaml,
+                        )
+                    }
+                    result => result,
+                }
+            })
+    } else {
+        match RouterConfig::load_config(&args.path) {
+            Err(ConfigError::UnresolvedEnvVars { vars }) if !args.strict => {
+                warnings.extend(unset_var_warnings(&vars));
+                RouterConfig::load_config_lenient(&args.path)
+            }
+            result => result,
+        }
+    };
+    let config = result.as_ref().ok().map(RouterConfig::sanitized);
+    let errors: Vec<String> = result
+        .as_ref()
+        .err()
+        .map(ToString::to_string)
+        .into_iter()
+        .collect();
+
+    match args.format {
+        OutputFormat::Json => {
+            let report = json!({
+                "path": args.path,
+                "valid": errors.is_empty(),
+                "config": config,
+                "warnings": warnings,
+                "errors": errors,
+            });
+            println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        }
+        OutputFormat::Text => {
+            for warning in &warnings {
+                eprintln!("warning: {}", warning);
+            }
+            for error in &errors {
+                eprintln!("error: {}", error);
+            }
+            if let Some(config) = &config {
+                print_summary(&args.path, config);
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn unset_var_warnings(vars: &[String]) -> Vec<String> {
+    vars.iter()
+        .map(|var| format!("Environment variable '{}' is not set", var))
+        .collect()
+}
+
+fn print_summary(path: &str, config: &RouterConfig) {
+    println!("{} is valid: {} policies", path, config.policies.len());
+    for policy in &config.policies {
+        println!("  policy '{}' -> {}", policy.name, policy.url);
+        for llm in &policy.llms {
+            println!(
+                "    llm '{}': model '{}' at {} (api_key {})",
+                llm.name, llm.model, llm.api_base, llm.api_key
+            );
+        }
+    }
+}
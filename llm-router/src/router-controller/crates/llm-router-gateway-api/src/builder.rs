@@ -0,0 +1,149 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builder
+use crate::config::{validate_config, Llm, Policy, Result, RouterConfig};
+use crate::error::ConfigError;
+
+/// Fluent construction of a `RouterConfig` for tests and embedders. `build`
+/// runs the same validation as the file loaders.
+#[derive(Debug, Default)]
+pub struct RouterConfigBuilder {
+    policies: Vec<Policy>,
+    /// First error hit while chaining, reported by `build`.
+    error: Option<ConfigError>,
+}
+
+impl RouterConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an empty policy routed through the Triton endpoint at `url`.
+    pub fn policy(mut self, name: &str, url: &str) -> Self {
+        self.policies.push(Policy {
+            name: name.to_string(),
+            url: url.to_string(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Appends `llm` to the policy named `policy_name`, which must already
+    /// have been added with `policy`.
+    pub fn llm(mut self, policy_name: &str, llm: Llm) -> Self {
+        match self
+            .policies
+            .iter_mut()
+            .find(|policy| policy.name.trim() == policy_name.trim())
+        {
+            Some(policy) => policy.llms.push(llm),
+            None => {
+                self.error.get_or_insert(ConfigError::UnknownPolicy {
+                    policy: policy_name.to_string(),
+                });
+            }
+        }
+        self
+    }
+
+    pub fn build(self) -> Result<RouterConfig> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let config = RouterConfig {
+            policies: self.policies,
+            ..Default::default()
+        };
+        validate_config(&config)?;
+        Ok(config)
+    }
+}
+
+impl RouterConfig {
+    pub fn builder() -> RouterConfigBuilder {
+        RouterConfigBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{test_llm, TRITON_URL};
+
+    fn llm(name: &str, model: &str) -> Llm {
+        Llm {
+            model: model.to_string(),
+            ..test_llm(name, None)
+        }
+    }
+
+    #[test]
+    fn test_builder_two_policies() {
+        let config = RouterConfig::builder()
+            .policy("task_router", TRITON_URL)
+            .policy("complexity_router", TRITON_URL)
+            .llm(
+                "task_router",
+                llm("Brainstorming", "meta/llama-3.1-70b-instruct"),
+            )
+            .llm(
+                "task_router",
+                llm("Chatbot", "mistralai/mixtral-8x22b-instruct-v0.1"),
+            )
+            .llm(
+                "complexity_router",
+                llm("Creativity", "meta/llama-3.1-8b-instruct"),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(config.policies.len(), 2);
+        let task_router = config.get_policy_by_name_ref("task_router").unwrap();
+        assert_eq!(task_router.llms.len(), 2);
+        assert_eq!(task_router.get_llm_name_by_index(1).unwrap(), "Chatbot");
+        let complexity_router = config.get_policy_by_name_ref("complexity_router").unwrap();
+        assert_eq!(
+            complexity_router.llms[0].model,
+            "meta/llama-3.1-8b-instruct"
+        );
+    }
+
+    #[test]
+    fn test_builder_llm_for_unknown_policy() {
+        let result = RouterConfig::builder()
+            .policy("task_router", TRITON_URL)
+            .llm("missing", llm("Chatbot", "meta/llama-3.1-8b-instruct"))
+            .build();
+        assert!(matches!(
+            result,
+            Err(ConfigError::UnknownPolicy { policy }) if policy == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_builder_runs_validation() {
+        let result = RouterConfig::builder()
+            .policy("task_router", TRITON_URL)
+            .llm("task_router", llm("Chatbot", "meta/llama-3.1-8b-instruct"))
+            .llm("task_router", llm("Chatbot", "meta/llama-3.1-70b-instruct"))
+            .build();
+        assert!(matches!(
+            result,
+            Err(ConfigError::DuplicateLlmName { policy, name })
+                if policy == "task_router" && name == "Chatbot"
+        ));
+    }
+}
@@ -0,0 +1,240 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Circuit breaker
+use crate::config::{CircuitBreakerConfig, Llm};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Where a `CircuitBreaker` is in its cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// Requests are refused until the cooldown has passed.
+    Open,
+    /// A limited number of trial requests decide whether to close again.
+    HalfOpen,
+}
+
+/// Runtime breaker built from an LLM's `circuit_breaker`. Clones share the
+/// same state, so one breaker can be handed to every request handler.
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    half_open_max_calls: u32,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed {
+        failures: u32,
+    },
+    Open {
+        since: Instant,
+    },
+    HalfOpen {
+        since: Instant,
+        calls: u32,
+        successes: u32,
+    },
+}
+
+impl CircuitBreaker {
+    /// Starts closed.
+    pub fn new(config: &CircuitBreakerConfig) -> Self {
+        Self {
+            failure_threshold: config.failure_threshold,
+            cooldown: Duration::from_millis(config.cooldown_ms),
+            half_open_max_calls: config.effective_half_open_max_calls(),
+            state: Arc::new(Mutex::new(State::Closed { failures: 0 })),
+        }
+    }
+
+    /// Whether a request may be sent now. An open breaker turns half-open
+    /// once the cooldown has passed, and a half-open one admits at most
+    /// `half_open_max_calls` requests until their results are recorded. Trial
+    /// requests whose results are still missing after another cooldown are
+    /// given up on, and a new round of trials starts, so a dropped request
+    /// cannot keep the breaker half-open forever.
+    pub fn allow_request(&self) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match &mut *state {
+            State::Closed { .. } => true,
+            State::Open { since } => {
+                if since.elapsed() < self.cooldown {
+                    return false;
+                }
+                *state = State::HalfOpen {
+                    since: Instant::now(),
+                    calls: 1,
+                    successes: 0,
+                };
+                true
+            }
+            State::HalfOpen { since, calls, .. } => {
+                if *calls < self.half_open_max_calls {
+                    *calls += 1;
+                    true
+                } else if since.elapsed() >= self.cooldown {
+                    *state = State::HalfOpen {
+                        since: Instant::now(),
+                        calls: 1,
+                        successes: 0,
+                    };
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Records the outcome of a request let through by `allow_request`.
+    /// Results arriving while the breaker is open are ignored.
+    pub fn record_result(&self, success: bool) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let open = State::Open {
+            since: Instant::now(),
+        };
+        match &mut *state {
+            State::Closed { failures } => {
+                if success {
+                    *failures = 0;
+                } else {
+                    *failures += 1;
+                    if *failures >= self.failure_threshold {
+                        *state = open;
+                    }
+                }
+            }
+            State::HalfOpen { successes, .. } => {
+                if !success {
+                    *state = open;
+                } else {
+                    *successes += 1;
+                    if *successes >= self.half_open_max_calls {
+                        *state = State::Closed { failures: 0 };
+                    }
+                }
+            }
+            State::Open { .. } => {}
+        }
+    }
+
+    pub fn state(&self) -> CircuitState {
+        match *self.state.lock().unwrap_or_else(|e| e.into_inner()) {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+}
+
+impl Llm {
+    /// A fresh breaker for this LLM, or `None` if it has no `circuit_breaker`.
+    pub fn circuit_breaker(&self) -> Option<CircuitBreaker> {
+        self.circuit_breaker.as_ref().map(CircuitBreaker::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    fn breaker(half_open_max_calls: Option<u32>) -> CircuitBreaker {
+        let llm = Llm {
+            name: "flaky".to_string(),
+            circuit_breaker: Some(CircuitBreakerConfig {
+                failure_threshold: 2,
+                cooldown_ms: 20,
+                half_open_max_calls,
+            }),
+            ..Default::default()
+        };
+        llm.circuit_breaker().unwrap()
+    }
+
+    #[test]
+    fn test_circuit_breaker_state_machine() {
+        assert_send_sync::<CircuitBreaker>();
+        let breaker = breaker(Some(2));
+        let shared = breaker.clone();
+
+        // A success resets the count of consecutive failures
+        breaker.record_result(false);
+        breaker.record_result(true);
+        breaker.record_result(false);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.allow_request());
+
+        shared.record_result(false);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow_request());
+
+        thread::sleep(Duration::from_millis(30));
+        assert!(breaker.allow_request());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(shared.allow_request());
+        assert!(!breaker.allow_request());
+
+        breaker.record_result(true);
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        shared.record_result(true);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.allow_request());
+    }
+
+    #[test]
+    fn test_circuit_breaker_reopens_on_half_open_failure() {
+        let breaker = breaker(None);
+        breaker.record_result(false);
+        breaker.record_result(false);
+        thread::sleep(Duration::from_millis(30));
+
+        assert!(breaker.allow_request());
+        assert!(!breaker.allow_request());
+        breaker.record_result(false);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow_request());
+    }
+
+    #[test]
+    fn test_circuit_breaker_retries_unanswered_half_open_trial() {
+        let breaker = breaker(None);
+        breaker.record_result(false);
+        breaker.record_result(false);
+        thread::sleep(Duration::from_millis(30));
+
+        // The trial request never reports back
+        assert!(breaker.allow_request());
+        assert!(!breaker.allow_request());
+        thread::sleep(Duration::from_millis(30));
+        assert!(breaker.allow_request());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        breaker.record_result(true);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_llm_without_circuit_breaker() {
+        assert!(Llm::default().circuit_breaker().is_none());
+    }
+}
@@ -0,0 +1,440 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Diff
+use crate::config::{is_sensitive_name, Llm, Policy, ProfileOverride, RouterConfig};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+
+/// LLM fields holding credentials, see `ConfigDiff::secrets_only`.
+const SECRET_FIELDS: &[&str] = &["api_key", "api_key_file"];
+
+/// Policy and LLM field whose changes are recorded per header, as
+/// `headers.<name>`.
+const HEADERS_FIELD: &str = "headers";
+
+/// Top-level field whose changes are recorded per LLM override, as
+/// `profiles.<profile>.<llm>.<field>`.
+const PROFILES_FIELD: &str = "profiles";
+
+/// Differences between two configs. Only names of policies, LLMs and fields
+/// are recorded, never values, so a diff is safe to log even when secrets
+/// such as `api_key` changed.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    /// Top-level fields other than `policies` whose values differ. A
+    /// `profiles` change is reported per LLM override field, as
+    /// `profiles.<profile>.<llm>.<field>`, or as `profiles.<profile>` and
+    /// `profiles.<profile>.<llm>` for added and removed entries.
+    pub changed_fields: Vec<String>,
+    pub added_policies: Vec<String>,
+    pub removed_policies: Vec<String>,
+    pub modified_policies: Vec<PolicyDiff>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PolicyDiff {
+    pub name: String,
+    /// Policy fields other than `llms` whose values differ.
+    pub changed_fields: Vec<String>,
+    pub added_llms: Vec<String>,
+    pub removed_llms: Vec<String>,
+    pub changed_llms: Vec<LlmDiff>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LlmDiff {
+    pub name: String,
+    pub changed_fields: Vec<String>,
+}
+
+impl ConfigDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changed_fields.is_empty()
+            && self.added_policies.is_empty()
+            && self.removed_policies.is_empty()
+            && self.modified_policies.is_empty()
+    }
+
+    /// Whether the configs differ only in credentials, so a reload can refresh
+    /// keys without rebuilding connection pools: an LLM's `api_key` or
+    /// `api_key_file`, the `api_key` of a profile's LLM override, or a policy
+    /// or LLM header with a secret-looking name.
+    /// False for an empty diff. Like the diff itself this looks at field and
+    /// header names only, never at the secret values.
+    pub fn secrets_only(&self) -> bool {
+        !self.is_empty()
+            && self
+                .changed_fields
+                .iter()
+                .all(|field| is_secret_profile_field(field))
+            && self.added_policies.is_empty()
+            && self.removed_policies.is_empty()
+            && self.modified_policies.iter().all(|policy| {
+                policy
+                    .changed_fields
+                    .iter()
+                    .all(|field| is_secret_header(field))
+                    && policy.added_llms.is_empty()
+                    && policy.removed_llms.is_empty()
+                    && policy.changed_llms.iter().all(|llm| {
+                        llm.changed_fields.iter().all(|field| {
+                            SECRET_FIELDS.contains(&field.as_str()) || is_secret_header(field)
+                        })
+                    })
+            })
+    }
+}
+
+impl RouterConfig {
+    /// Describes what changed going from `self` to `other`. Policies and LLMs
+    /// are matched by trimmed name, so a rename shows up as a removal plus an
+    /// addition.
+    pub fn diff(&self, other: &RouterConfig) -> ConfigDiff {
+        let mut diff = ConfigDiff {
+            changed_fields: changed_fields(self, other, "policies"),
+            ..Default::default()
+        };
+        if let Some(index) = diff
+            .changed_fields
+            .iter()
+            .position(|field| field == PROFILES_FIELD)
+        {
+            let fields = changed_profile_fields(&self.profiles, &other.profiles);
+            diff.changed_fields.splice(index..=index, fields);
+        }
+
+        for old in &self.policies {
+            match find_policy(other, &old.name) {
+                Some(new) => {
+                    let policy_diff = diff_policy(old, new);
+                    if !policy_diff.is_empty() {
+                        diff.modified_policies.push(policy_diff);
+                    }
+                }
+                None => diff.removed_policies.push(old.name.clone()),
+            }
+        }
+        for new in &other.policies {
+            if find_policy(self, &new.name).is_none() {
+                diff.added_policies.push(new.name.clone());
+            }
+        }
+        diff
+    }
+}
+
+impl PolicyDiff {
+    fn is_empty(&self) -> bool {
+        self.changed_fields.is_empty()
+            && self.added_llms.is_empty()
+            && self.removed_llms.is_empty()
+            && self.changed_llms.is_empty()
+    }
+}
+
+fn find_policy<'a>(config: &'a RouterConfig, name: &str) -> Option<&'a Policy> {
+    config
+        .policies
+        .iter()
+        .find(|policy| policy.name.trim() == name.trim())
+}
+
+fn find_llm<'a>(policy: &'a Policy, name: &str) -> Option<&'a Llm> {
+    policy
+        .llms
+        .iter()
+        .find(|llm| llm.name.trim() == name.trim())
+}
+
+fn diff_policy(old: &Policy, new: &Policy) -> PolicyDiff {
+    let mut diff = PolicyDiff {
+        name: new.name.clone(),
+        changed_fields: changed_fields(old, new, "llms"),
+        ..Default::default()
+    };
+    for old_llm in &old.llms {
+        match find_llm(new, &old_llm.name) {
+            Some(new_llm) => {
+                let fields = changed_fields(old_llm, new_llm, "");
+                if !fields.is_empty() {
+                    diff.changed_llms.push(LlmDiff {
+                        name: new_llm.name.clone(),
+                        changed_fields: fields,
+                    });
+                }
+            }
+            None => diff.removed_llms.push(old_llm.name.clone()),
+        }
+    }
+    for new_llm in &new.llms {
+        if find_llm(old, &new_llm.name).is_none() {
+            diff.added_llms.push(new_llm.name.clone());
+        }
+    }
+    diff
+}
+
+/// Changed profile entries, named as in `ConfigDiff::changed_fields`.
+fn changed_profile_fields(
+    old: &HashMap<String, ProfileOverride>,
+    new: &HashMap<String, ProfileOverride>,
+) -> Vec<String> {
+    let mut fields = Vec::new();
+    for profile in old.keys().chain(new.keys()).collect::<BTreeSet<_>>() {
+        let prefix = format!("{}.{}", PROFILES_FIELD, profile);
+        let (Some(old), Some(new)) = (old.get(profile), new.get(profile)) else {
+            fields.push(prefix);
+            continue;
+        };
+        for llm in old
+            .llms
+            .keys()
+            .chain(new.llms.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            match (old.llms.get(llm), new.llms.get(llm)) {
+                (Some(old), Some(new)) => fields.extend(
+                    changed_fields(old, new, "")
+                        .into_iter()
+                        .map(|field| format!("{}.{}.{}", prefix, llm, field)),
+                ),
+                _ => fields.push(format!("{}.{}", prefix, llm)),
+            }
+        }
+    }
+    fields
+}
+
+/// Whether `field` is a changed credential of a profile's LLM override.
+fn is_secret_profile_field(field: &str) -> bool {
+    field
+        .strip_prefix(PROFILES_FIELD)
+        .and_then(|rest| rest.strip_prefix('.'))
+        .and_then(|rest| rest.split_once('.'))
+        .and_then(|(_, rest)| rest.rsplit_once('.'))
+        .is_some_and(|(_, field)| SECRET_FIELDS.contains(&field))
+}
+
+/// Whether `field` is a changed header whose name looks like it carries a
+/// secret.
+fn is_secret_header(field: &str) -> bool {
+    field
+        .strip_prefix(HEADERS_FIELD)
+        .and_then(|rest| rest.strip_prefix('.'))
+        .is_some_and(is_sensitive_name)
+}
+
+/// Names of the serialized fields whose values differ, in sorted order. A
+/// `headers` change is reported as `headers.<name>` for each header whose
+/// value differs, unset headers counting as none.
+fn changed_fields<T: Serialize>(old: &T, new: &T, skip: &str) -> Vec<String> {
+    let to_object = |value: Value| match value {
+        Value::Object(map) => map,
+        _ => serde_json::Map::new(),
+    };
+    let (old, new) = (
+        to_object(serde_json::to_value(old).unwrap_or_default()),
+        to_object(serde_json::to_value(new).unwrap_or_default()),
+    );
+    let mut fields = Vec::new();
+    for key in old
+        .keys()
+        .chain(new.keys())
+        .filter(|key| key.as_str() != skip)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|key| old.get(*key) != new.get(*key))
+    {
+        if key != HEADERS_FIELD {
+            fields.push(key.clone());
+            continue;
+        }
+        let headers = |map: &serde_json::Map<String, Value>| {
+            to_object(map.get(key).cloned().unwrap_or_default())
+        };
+        let (old_headers, new_headers) = (headers(&old), headers(&new));
+        fields.extend(
+            old_headers
+                .keys()
+                .chain(new_headers.keys())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .filter(|name| old_headers.get(*name) != new_headers.get(*name))
+                .map(|name| format!("{}.{}", HEADERS_FIELD, name)),
+        );
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LlmOverride;
+    use crate::test_fixtures::{test_config, test_llm, test_policy};
+
+    fn llm(name: &str, model: &str, api_key: &str) -> Llm {
+        Llm {
+            model: model.to_string(),
+            api_key: api_key.to_string(),
+            ..test_llm(name, None)
+        }
+    }
+
+    #[test]
+    fn test_diff_identical_configs_is_empty() {
+        let old = test_config(vec![test_policy("a", vec![llm("x", "model-x", "key")])]);
+        assert!(old.diff(&old.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_rename_model_change_and_addition() {
+        let old = test_config(vec![
+            test_policy("task_router", vec![llm("Chatbot", "model-a", "old-secret")]),
+            test_policy(
+                "complexity_router",
+                vec![llm("Reasoning", "model-r", "key")],
+            ),
+        ]);
+        let new = test_config(vec![
+            test_policy("task_router", vec![llm("Chatbot", "model-b", "new-secret")]),
+            test_policy(
+                "complexity_router_v2",
+                vec![llm("Reasoning", "model-r", "key")],
+            ),
+            test_policy("code_router", vec![llm("Code", "model-c", "key")]),
+        ]);
+
+        let diff = old.diff(&new);
+        assert!(diff.changed_fields.is_empty());
+        assert_eq!(diff.removed_policies, vec!["complexity_router"]);
+        assert_eq!(
+            diff.added_policies,
+            vec!["complexity_router_v2", "code_router"]
+        );
+        assert_eq!(
+            diff.modified_policies,
+            vec![PolicyDiff {
+                name: "task_router".to_string(),
+                changed_llms: vec![LlmDiff {
+                    name: "Chatbot".to_string(),
+                    changed_fields: vec!["api_key".to_string(), "model".to_string()],
+                }],
+                ..Default::default()
+            }]
+        );
+
+        let json = serde_json::to_string(&diff).unwrap();
+        assert!(json.contains("api_key"));
+        assert!(!json.contains("old-secret"));
+        assert!(!json.contains("new-secret"));
+    }
+
+    #[test]
+    fn test_secrets_only() {
+        let old = test_config(vec![test_policy(
+            "task_router",
+            vec![
+                llm("Chatbot", "model-a", "old-secret"),
+                llm("Code", "model-c", "key"),
+            ],
+        )]);
+        let rotated = test_config(vec![test_policy(
+            "task_router",
+            vec![
+                llm("Chatbot", "model-a", "new-secret"),
+                llm("Code", "model-c", "key"),
+            ],
+        )]);
+        assert!(old.diff(&rotated).secrets_only());
+
+        let mut remodeled = rotated.clone();
+        remodeled.policies[0].llms[1].model = "model-d".to_string();
+        assert!(!old.diff(&remodeled).secrets_only());
+
+        assert!(!old.diff(&old.clone()).secrets_only());
+
+        let with_header = |policy_header: &str, llm_header: &str| {
+            let mut config = old.clone();
+            config.policies[0].headers = Some(HashMap::from([(
+                "X-Auth-Token".to_string(),
+                policy_header.to_string(),
+            )]));
+            config.policies[0].llms[0].headers = Some(HashMap::from([
+                ("api-key".to_string(), llm_header.to_string()),
+                ("anthropic-version".to_string(), "2023-06-01".to_string()),
+            ]));
+            config
+        };
+        let before = with_header("old-token", "old-key");
+        let diff = before.diff(&with_header("new-token", "new-key"));
+        assert_eq!(
+            diff.modified_policies[0].changed_fields,
+            ["headers.X-Auth-Token"]
+        );
+        assert_eq!(
+            diff.modified_policies[0].changed_llms[0].changed_fields,
+            ["headers.api-key"]
+        );
+        assert!(diff.secrets_only());
+
+        let mut versioned = before.clone();
+        versioned.policies[0].llms[0]
+            .headers
+            .as_mut()
+            .unwrap()
+            .insert("anthropic-version".to_string(), "2024-01-01".to_string());
+        assert!(!before.diff(&versioned).secrets_only());
+    }
+
+    #[test]
+    fn test_secrets_only_covers_profile_keys() {
+        let profile = |api_key: &str, model: Option<&str>| {
+            let mut config = test_config(vec![test_policy(
+                "task_router",
+                vec![llm("Chatbot", "model-a", "key")],
+            )]);
+            let llm_override = LlmOverride {
+                api_key: Some(api_key.to_string()),
+                model: model.map(str::to_string),
+                ..Default::default()
+            };
+            config.profiles.insert(
+                "staging".to_string(),
+                ProfileOverride {
+                    llms: HashMap::from([("Chatbot".to_string(), llm_override)]),
+                },
+            );
+            config
+        };
+
+        let old = profile("old-secret", None);
+        let diff = old.diff(&profile("new-secret", None));
+        assert_eq!(diff.changed_fields, ["profiles.staging.Chatbot.api_key"]);
+        assert!(diff.secrets_only());
+
+        let diff = old.diff(&profile("old-secret", Some("model-b")));
+        assert_eq!(diff.changed_fields, ["profiles.staging.Chatbot.model"]);
+        assert!(!diff.secrets_only());
+
+        let mut added = old.clone();
+        added
+            .profiles
+            .insert("prod".to_string(), ProfileOverride::default());
+        assert_eq!(old.diff(&added).changed_fields, ["profiles.prod"]);
+        assert!(!old.diff(&added).secrets_only());
+    }
+}
@@ -0,0 +1,467 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Edit
+use crate::config::{ConfigFormat, Result, RouterConfig};
+use crate::error::ConfigError;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use yaml_rust2::parser::Parser;
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+use yaml_rust2::Event;
+
+impl RouterConfig {
+    /// Sets the `model` of one LLM directly in a YAML config file. Only that
+    /// scalar is rewritten, keeping its quoting style, so comments, ordering and
+    /// formatting elsewhere in the file are untouched. The edited config is
+    /// validated like `load_config_lenient`, so placeholders for variables not
+    /// set in this process do not block the edit. It then replaces the file
+    /// atomically through a temporary file next to it, keeping the file's
+    /// permissions; a symlinked config is edited at its target. On an unknown
+    /// policy or LLM, or an edit that does not validate, the file is left as
+    /// is.
+    pub fn set_llm_model(path: &str, policy: &str, llm: &str, model: &str) -> Result<()> {
+        if ConfigFormat::from_path(path) != ConfigFormat::Yaml {
+            let extension = Path::new(path)
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .unwrap_or_default();
+            return Err(ConfigError::UnsupportedFormat {
+                extension: extension.to_string(),
+            });
+        }
+        let content = std::fs::read_to_string(path)?;
+        // Surfaces syntax errors the same way loading would
+        crate::config::parse_tree(&content, ConfigFormat::Yaml)?;
+
+        let field = format!("{}.{}.model", policy, llm);
+        let scalars = policy_scalars(&content).map_err(|message| ConfigError::UneditableField {
+            field: field.clone(),
+            message,
+        })?;
+        let key = |segments: &[&str]| segments.join("/");
+        // Every policy and LLM has at least one scalar, which bounds the indices
+        let policy_index = (0..scalars.len())
+            .position(|i| {
+                scalars
+                    .get(&key(&["policies", &i.to_string(), "name"]))
+                    .is_some_and(|name| name.value.trim() == policy.trim())
+            })
+            .ok_or_else(|| ConfigError::UnknownPolicy {
+                policy: policy.to_string(),
+            })?
+            .to_string();
+        let llm_index = (0..scalars.len())
+            .position(|j| {
+                scalars
+                    .get(&key(&[
+                        "policies",
+                        &policy_index,
+                        "llms",
+                        &j.to_string(),
+                        "name",
+                    ]))
+                    .is_some_and(|name| name.value.trim() == llm.trim())
+            })
+            .ok_or_else(|| ConfigError::UnknownLlm {
+                policy: policy.to_string(),
+                llm: llm.to_string(),
+            })?
+            .to_string();
+        let current = scalars
+            .get(&key(&[
+                "policies",
+                &policy_index,
+                "llms",
+                &llm_index,
+                "model",
+            ]))
+            .ok_or_else(|| ConfigError::MissingLlmField {
+                llm: llm.to_string(),
+                field: "model".to_string(),
+            })?;
+
+        let uneditable = |message: &str| ConfigError::UneditableField {
+            field: field.clone(),
+            message: message.to_string(),
+        };
+        // Markers count characters, not bytes
+        let start = content
+            .char_indices()
+            .nth(current.mark.index())
+            .map_or(content.len(), |(offset, _)| offset);
+        let end = scalar_end(&content, start, current)
+            .ok_or_else(|| uneditable("multi-line scalars are not supported"))?;
+        let replacement = match current.style {
+            TScalarStyle::DoubleQuoted => serde_json::to_string(model)?,
+            TScalarStyle::SingleQuoted => format!("'{}'", model.replace('\'', "''")),
+            TScalarStyle::Plain => {
+                let rendered = serde_yaml::to_string(model)?;
+                let rendered = rendered.trim_end();
+                if rendered.contains('\n') {
+                    serde_json::to_string(model)?
+                } else {
+                    rendered.to_string()
+                }
+            }
+            TScalarStyle::Literal | TScalarStyle::Folded => {
+                return Err(uneditable("block scalars are not supported"))
+            }
+        };
+
+        let mut edited = String::with_capacity(content.len() + replacement.len());
+        edited.push_str(&content[..start]);
+        edited.push_str(&replacement);
+        edited.push_str(&content[end..]);
+        RouterConfig::load_from_reader_lenient(edited.as_bytes(), ConfigFormat::Yaml)?;
+
+        // Replacing a symlink would turn it into a regular file
+        let target = std::fs::canonicalize(path)?;
+        let dir = target.parent().unwrap_or(Path::new("."));
+        let mut file = tempfile::NamedTempFile::new_in(dir)?;
+        file.write_all(edited.as_bytes())?;
+        // The temporary file is private to its owner
+        std::fs::set_permissions(file.path(), std::fs::metadata(&target)?.permissions())?;
+        file.as_file().sync_all()?;
+        file.persist(&target).map_err(|err| err.error)?;
+        Ok(())
+    }
+}
+
+struct Scalar {
+    value: String,
+    style: TScalarStyle,
+    mark: Marker,
+}
+
+enum Frame {
+    Map { key: Option<String> },
+    Seq { index: usize },
+}
+
+/// Scalars under `policies`, keyed by their path with `/` separated keys and
+/// sequence indices, e.g. `policies/0/llms/1/model`. Multiple documents are
+/// numbered the way loading combines them: a document with a `policies` key
+/// contributes its policies, any other non-empty document is one policy.
+fn policy_scalars(content: &str) -> std::result::Result<HashMap<String, Scalar>, String> {
+    fn advance(stack: &mut [Frame]) {
+        match stack.last_mut() {
+            Some(Frame::Map { key }) => *key = None,
+            Some(Frame::Seq { index }) => *index += 1,
+            None => {}
+        }
+    }
+    fn is_key(stack: &[Frame]) -> bool {
+        matches!(stack.last(), Some(Frame::Map { key: None }))
+    }
+    fn path(stack: &[Frame]) -> String {
+        stack
+            .iter()
+            .map(|frame| match frame {
+                Frame::Map { key } => key.clone().unwrap_or_default(),
+                Frame::Seq { index } => index.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    let mut scalars = HashMap::new();
+    // Policies in earlier documents
+    let mut offset = 0;
+    // Per document: its scalars by path, whether it has a top-level
+    // `policies` key, how many policies that holds and whether it is empty
+    let mut document = HashMap::new();
+    let mut has_policies = false;
+    let mut policy_count = 0;
+    let mut is_empty = true;
+    let mut stack = Vec::new();
+    let mut parser = Parser::new_from_str(content);
+    loop {
+        let (event, mark) = parser.next_token().map_err(|err| err.to_string())?;
+        match event {
+            Event::Scalar(value, style, ..) => {
+                let top_level = stack.len() == 1;
+                if let (true, Some(Frame::Map { key })) = (is_key(&stack), stack.last_mut()) {
+                    has_policies |= top_level && value == "policies";
+                    *key = Some(value);
+                    continue;
+                }
+                if stack.is_empty() {
+                    is_empty = style == TScalarStyle::Plain
+                        && matches!(value.as_str(), "" | "~" | "null" | "Null" | "NULL");
+                    continue;
+                }
+                document.insert(path(&stack), Scalar { value, style, mark });
+                advance(&mut stack);
+            }
+            Event::Alias(_) => match stack.last_mut() {
+                Some(Frame::Map { key: key @ None }) => *key = Some(String::new()),
+                _ => advance(&mut stack),
+            },
+            Event::MappingStart(..) => {
+                is_empty &= !stack.is_empty();
+                stack.push(Frame::Map { key: None });
+            }
+            Event::SequenceStart(..) => {
+                is_empty &= !stack.is_empty();
+                stack.push(Frame::Seq { index: 0 });
+            }
+            Event::MappingEnd | Event::SequenceEnd => {
+                if let (Some(Frame::Seq { index }), [Frame::Map { key: Some(key) }, _]) =
+                    (stack.last(), stack.as_slice())
+                {
+                    if key == "policies" {
+                        policy_count = *index;
+                    }
+                }
+                stack.pop();
+                match stack.last_mut() {
+                    // A complex key, never one of ours
+                    Some(Frame::Map { key: key @ None }) => *key = Some(String::new()),
+                    _ => advance(&mut stack),
+                }
+            }
+            Event::DocumentEnd => {
+                for (path, scalar) in document.drain() {
+                    let path = if has_policies {
+                        let Some((index, rest)) = path
+                            .strip_prefix("policies/")
+                            .and_then(|path| path.split_once('/'))
+                            .and_then(|(index, rest)| Some((index.parse::<usize>().ok()?, rest)))
+                        else {
+                            continue;
+                        };
+                        format!("policies/{}/{}", offset + index, rest)
+                    } else {
+                        format!("policies/{}/{}", offset, path)
+                    };
+                    scalars.insert(path, scalar);
+                }
+                offset += match (has_policies, is_empty) {
+                    (true, _) => policy_count,
+                    (false, true) => 0,
+                    (false, false) => 1,
+                };
+                has_policies = false;
+                policy_count = 0;
+                is_empty = true;
+            }
+            Event::StreamEnd => return Ok(scalars),
+            Event::Nothing | Event::StreamStart | Event::DocumentStart => {}
+        }
+    }
+}
+
+/// Byte offset just past a single-line scalar starting at `start`.
+fn scalar_end(content: &str, start: usize, scalar: &Scalar) -> Option<usize> {
+    let rest = &content[start..];
+    let line_end = rest.find('\n').unwrap_or(rest.len());
+    let line = &rest[..line_end];
+    let len = match scalar.style {
+        TScalarStyle::DoubleQuoted => {
+            let mut chars = line.char_indices().skip(1);
+            loop {
+                match chars.next()? {
+                    (_, '\\') => {
+                        chars.next()?;
+                    }
+                    (i, '"') => break i + 1,
+                    _ => {}
+                }
+            }
+        }
+        TScalarStyle::SingleQuoted => {
+            let mut chars = line.char_indices().skip(1).peekable();
+            loop {
+                match chars.next()? {
+                    (_, '\'') if chars.peek().is_some_and(|(_, c)| *c == '\'') => {
+                        chars.next();
+                    }
+                    (i, '\'') => break i + 1,
+                    _ => {}
+                }
+            }
+        }
+        _ => {
+            let plain = line.find(" #").map_or(line, |comment| &line[..comment]);
+            let plain = plain.trim_end();
+            // A plain scalar continued on the next line
+            if plain != scalar.value {
+                return None;
+            }
+            plain.len()
+        }
+    };
+    Some(start + len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EDIT_CONFIG: &str = r#"# Router config
+policies:
+  - name: "task_router"
+    url: http://router:8000/v2/models/task_router/infer
+    llms:
+      # Brainstorming needs a large model
+      - name: Brainstorming
+        api_base: https://integrate.api.nvidia.com
+        api_key: "${CONFIG_TEST_EDIT_KEY:-test-key}"
+        # Keep in sync with the NIM catalog – model ids change
+        model: meta/llama-3.1-70b-instruct # latest
+      - name: "Chatbot"
+        api_base: https://integrate.api.nvidia.com
+        api_key: test-key
+        model: "mistralai/mixtral-8x22b-instruct-v0.1"
+"#;
+
+    fn write_config(content: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_set_llm_model_preserves_comments() {
+        let file = write_config(EDIT_CONFIG);
+        let path = file.path().to_str().unwrap();
+
+        RouterConfig::set_llm_model(path, "task_router", "Brainstorming", "meta/llama-3.3-70b")
+            .unwrap();
+        RouterConfig::set_llm_model(path, "task_router", "Chatbot", "nv/\"quoted\"").unwrap();
+
+        let edited = std::fs::read_to_string(path).unwrap();
+        assert_eq!(
+            edited,
+            EDIT_CONFIG
+                .replace(
+                    "model: meta/llama-3.1-70b-instruct # latest",
+                    "model: meta/llama-3.3-70b # latest"
+                )
+                .replace(
+                    "\"mistralai/mixtral-8x22b-instruct-v0.1\"",
+                    r#""nv/\"quoted\"""#
+                )
+        );
+        let config = RouterConfig::load_config(path).unwrap();
+        assert_eq!(config.policies[0].llms[0].model, "meta/llama-3.3-70b");
+        assert_eq!(config.policies[0].llms[1].model, "nv/\"quoted\"");
+    }
+
+    #[test]
+    fn test_set_llm_model_unknown_target_leaves_file() {
+        let file = write_config(EDIT_CONFIG);
+        let path = file.path().to_str().unwrap();
+
+        let result = RouterConfig::set_llm_model(path, "missing", "Brainstorming", "m");
+        assert!(
+            matches!(result, Err(ConfigError::UnknownPolicy { policy }) if policy == "missing")
+        );
+
+        let result = RouterConfig::set_llm_model(path, "task_router", "missing", "m");
+        assert!(matches!(
+            result,
+            Err(ConfigError::UnknownLlm { policy, llm }) if policy == "task_router" && llm == "missing"
+        ));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), EDIT_CONFIG);
+    }
+
+    #[test]
+    fn test_set_llm_model_rejects_invalid_edit() {
+        let file = write_config(EDIT_CONFIG);
+        let path = file.path().to_str().unwrap();
+
+        let result = RouterConfig::set_llm_model(path, "task_router", "Brainstorming", "");
+        assert!(matches!(
+            result,
+            Err(ConfigError::MissingLlmField { llm, field })
+                if llm == "Brainstorming" && field == "model"
+        ));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), EDIT_CONFIG);
+    }
+
+    #[test]
+    fn test_set_llm_model_in_multi_document_file() {
+        let content = format!(
+            "{}---\n{}",
+            EDIT_CONFIG,
+            r#"name: standalone
+url: http://router:8000/v2/models/standalone/infer
+llms:
+  - name: Brainstorming
+    api_base: https://integrate.api.nvidia.com
+    api_key: test-key
+    model: meta/llama-3.1-8b-instruct
+"#
+        );
+        let file = write_config(&content);
+        let path = file.path().to_str().unwrap();
+
+        RouterConfig::set_llm_model(path, "standalone", "Brainstorming", "meta/llama-3.3-70b")
+            .unwrap();
+        RouterConfig::set_llm_model(path, "task_router", "Chatbot", "nv/chat").unwrap();
+
+        let config = RouterConfig::load_config(path).unwrap();
+        assert_eq!(
+            config.policies[0].llms[0].model,
+            "meta/llama-3.1-70b-instruct"
+        );
+        assert_eq!(config.policies[0].llms[1].model, "nv/chat");
+        assert_eq!(config.policies[1].name, "standalone");
+        assert_eq!(config.policies[1].llms[0].model, "meta/llama-3.3-70b");
+    }
+
+    #[test]
+    fn test_set_llm_model_with_unset_placeholder() {
+        std::env::remove_var("CONFIG_TEST_EDIT_UNSET");
+        let content =
+            EDIT_CONFIG.replace("api_key: test-key", "api_key: ${CONFIG_TEST_EDIT_UNSET}");
+        let file = write_config(&content);
+        let path = file.path().to_str().unwrap();
+
+        RouterConfig::set_llm_model(path, "task_router", "Chatbot", "nv/chat").unwrap();
+        let edited = std::fs::read_to_string(path).unwrap();
+        assert!(edited.contains("api_key: ${CONFIG_TEST_EDIT_UNSET}"));
+        assert!(edited.contains("model: \"nv/chat\""));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_set_llm_model_keeps_permissions_and_symlink() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("router.yaml");
+        std::fs::write(&target, EDIT_CONFIG).unwrap();
+        std::fs::set_permissions(&target, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let link = dir.path().join("current.yaml");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        RouterConfig::set_llm_model(link.to_str().unwrap(), "task_router", "Chatbot", "nv/chat")
+            .unwrap();
+
+        assert!(std::fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        let mode = std::fs::metadata(&target).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o644);
+        assert!(std::fs::read_to_string(&target)
+            .unwrap()
+            .contains("model: \"nv/chat\""));
+    }
+}
@@ -0,0 +1,274 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Env Vars
+use crate::config::{
+    is_request_template, parse_tree, read_config_file, ConfigFormat, PlaceholderStyle, Result,
+    RouterConfig,
+};
+use serde::Serialize;
+use serde_yaml::Value;
+
+/// An environment variable referenced by a config placeholder.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct EnvVarRequirement {
+    pub name: String,
+    /// True when every reference has a `:-` default, so the config also
+    /// loads without the variable.
+    pub optional: bool,
+    /// The first `:-` default given for the variable, unexpanded.
+    pub default: Option<String>,
+}
+
+impl RouterConfig {
+    /// Environment variables referenced by `${VAR}` placeholders in the config
+    /// at `path`, in order of first use, without substituting or validating
+    /// anything. Like substitution, this only looks at string values and keys,
+    /// so placeholders in comments and `$${VAR}` escapes are not reported,
+    /// nor are the request field placeholders of a policy's
+    /// `request_template`. Variables nested in a default, as `B` in
+    /// `${A:-${B}}`, are reported too.
+    pub fn required_env_vars(path: &str) -> Result<Vec<EnvVarRequirement>> {
+        Self::required_env_vars_with_style(path, PlaceholderStyle::Dollar)
+    }
+
+    /// `required_env_vars` for placeholders written in `style`, matching
+    /// `LoadOptions::placeholder_style`.
+    pub fn required_env_vars_with_style(
+        path: &str,
+        style: PlaceholderStyle,
+    ) -> Result<Vec<EnvVarRequirement>> {
+        let content = read_config_file(path)?;
+        let value = parse_tree(&content, ConfigFormat::from_path(path))?;
+        let mut vars = Vec::new();
+        collect_value(&value, &mut Vec::new(), style, &mut vars);
+        Ok(vars)
+    }
+
+    /// A `.env` style template with an empty assignment, e.g. `OPENAI_KEY=`,
+    /// for every variable `required_env_vars` reports, for bootstrapping an
+    /// environment. Defaults are noted in a comment above their variable.
+    /// Values are never read from the current environment.
+    pub fn required_env_template(path: &str) -> Result<String> {
+        let mut template = String::new();
+        for var in Self::required_env_vars(path)? {
+            if let Some(default) = &var.default {
+                template.push_str(&format!("# default: {}\n", default));
+            }
+            template.push_str(&format!("{}=\n", var.name));
+        }
+        Ok(template)
+    }
+}
+
+/// Walks `value` like config expansion, skipping the same paths.
+fn collect_value(
+    value: &Value,
+    path: &mut Vec<String>,
+    style: PlaceholderStyle,
+    vars: &mut Vec<EnvVarRequirement>,
+) {
+    if is_request_template(path) {
+        return;
+    }
+    match value {
+        Value::String(text) => collect_text(text, style, vars),
+        Value::Sequence(items) => {
+            for (index, item) in items.iter().enumerate() {
+                path.push(index.to_string());
+                collect_value(item, path, style, vars);
+                path.pop();
+            }
+        }
+        Value::Mapping(mapping) => {
+            for (key, item) in mapping {
+                collect_value(key, path, style, vars);
+                path.push(key.as_str().unwrap_or_default().to_string());
+                collect_value(item, path, style, vars);
+                path.pop();
+            }
+        }
+        Value::Tagged(tagged) => collect_value(&tagged.value, path, style, vars),
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Mirrors the placeholder syntax handled by config expansion.
+fn collect_text(text: &str, style: PlaceholderStyle, vars: &mut Vec<EnvVarRequirement>) {
+    let mut rest = text;
+    while let Some(start) = style.next_candidate(rest) {
+        rest = &rest[start..];
+        if let Some(escaped) = rest.strip_prefix(style.escape()) {
+            rest = escaped;
+        } else if let Some((body, len)) = style.parse(rest) {
+            let (name, modifier) = match body.find(':') {
+                Some(index) => (&body[..index], Some(&body[index..])),
+                None => (body, None),
+            };
+            let default = modifier.and_then(|modifier| modifier.strip_prefix(":-"));
+            record(vars, name, default);
+            if let Some(default) = default {
+                collect_text(default, style, vars);
+            }
+            rest = &rest[len..];
+        } else if rest.starts_with(style.open()) {
+            // Unterminated, so expansion keeps the remainder as-is
+            return;
+        } else {
+            rest = &rest[1..];
+        }
+    }
+}
+
+fn record(vars: &mut Vec<EnvVarRequirement>, name: &str, default: Option<&str>) {
+    match vars.iter_mut().find(|var| var.name == name) {
+        Some(var) => {
+            var.optional &= default.is_some();
+            if var.default.is_none() {
+                var.default = default.map(str::to_string);
+            }
+        }
+        None => vars.push(EnvVarRequirement {
+            name: name.to_string(),
+            optional: default.is_some(),
+            default: default.map(str::to_string),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const ENV_CONFIG: &str = r#"
+# ${IN_COMMENT} is not substituted
+policies:
+  - name: "${CONFIG_TEST_POLICY_NAME:-task_router}"
+    url: ${ROUTER_URL:?router url is required}
+    llms:
+      - name: Brainstorming
+        api_base: "${API_BASE:-${FALLBACK_API_BASE}}"
+        api_key: ${NVIDIA_API_KEY}
+        model: "$${LITERAL} meta/llama-3.1-70b-instruct"
+        weight: ${WEIGHT:-1}
+      - name: Chatbot
+        api_base: ${API_BASE}
+        api_key: ${NVIDIA_API_KEY}
+        model: meta/llama-3.1-70b-instruct
+"#;
+
+    fn write_config(content: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_required_env_vars() {
+        let file = write_config(ENV_CONFIG);
+        let vars = RouterConfig::required_env_vars(file.path().to_str().unwrap()).unwrap();
+        let summary: Vec<_> = vars
+            .iter()
+            .map(|var| (var.name.as_str(), var.optional))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("CONFIG_TEST_POLICY_NAME", true),
+                ("ROUTER_URL", false),
+                ("API_BASE", false),
+                ("FALLBACK_API_BASE", false),
+                ("NVIDIA_API_KEY", false),
+                ("WEIGHT", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_request_template_placeholders_are_not_required() {
+        let content = ENV_CONFIG.replace(
+            "    llms:",
+            "    request_template:\n      metadata:\n        history: ${messages}\n    llms:",
+        );
+        let file = write_config(&content);
+        let path = file.path().to_str().unwrap();
+
+        let vars = RouterConfig::required_env_vars(path).unwrap();
+        assert!(vars.iter().all(|var| var.name != "messages"));
+        assert!(!RouterConfig::required_env_template(path)
+            .unwrap()
+            .contains("messages="));
+    }
+
+    #[test]
+    fn test_required_env_template() {
+        std::env::set_var("CONFIG_TEST_POLICY_NAME", "resolved-secret");
+        let file = write_config(ENV_CONFIG);
+
+        let template = RouterConfig::required_env_template(file.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            template,
+            "# default: task_router\n\
+             CONFIG_TEST_POLICY_NAME=\n\
+             ROUTER_URL=\n\
+             # default: ${FALLBACK_API_BASE}\n\
+             API_BASE=\n\
+             FALLBACK_API_BASE=\n\
+             NVIDIA_API_KEY=\n\
+             # default: 1\n\
+             WEIGHT=\n"
+        );
+        assert!(!template.contains("resolved-secret"));
+    }
+
+    #[test]
+    fn test_required_env_vars_with_double_brace_style() {
+        // Unquoted, YAML would read a value starting with `{{` as a flow mapping
+        let content: String = ENV_CONFIG
+            // An escaped backslash in this double-quoted value
+            .replace("$${", "\\\\{{")
+            .replace("${", "{{")
+            .replace('}', "}}")
+            .lines()
+            .map(|line| match line.split_once(": {{") {
+                Some((key, value)) => format!("{}: '{{{{{}'\n", key, value),
+                None => format!("{}\n", line),
+            })
+            .collect();
+        let file = write_config(&content);
+        let path = file.path().to_str().unwrap();
+
+        let names = |style| -> Vec<String> {
+            RouterConfig::required_env_vars_with_style(path, style)
+                .unwrap()
+                .into_iter()
+                .map(|var| var.name)
+                .collect()
+        };
+        assert_eq!(
+            names(PlaceholderStyle::DoubleBrace),
+            [
+                "CONFIG_TEST_POLICY_NAME",
+                "ROUTER_URL",
+                "API_BASE",
+                "FALLBACK_API_BASE",
+                "NVIDIA_API_KEY",
+                "WEIGHT",
+            ]
+        );
+        assert!(names(PlaceholderStyle::Dollar).is_empty());
+    }
+}
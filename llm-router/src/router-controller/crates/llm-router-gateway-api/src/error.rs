@@ -111,10 +111,142 @@ pub enum ConfigError {
     MissingPolicyField { policy: String, field: String },
     #[error("Missing field '{field}' in LLM '{llm}'")]
     MissingLlmField { llm: String, field: String },
+    #[error("Environment variable '{var}' is required: {message}")]
+    MissingEnvVar { var: String, message: String },
+    #[error("Unresolved environment variables: {}", vars.join(", "))]
+    UnresolvedEnvVars { vars: Vec<String> },
+    #[error("Policy '{policy}' has no LLMs")]
+    EmptyPolicy { policy: String },
+    #[error("Policy '{policy}' has no enabled LLMs")]
+    NoEnabledLlms { policy: String },
+    #[error("All LLM weights in policy '{policy}' are zero")]
+    InvalidWeights { policy: String },
+    #[error("Timeout for LLM '{llm}' must be greater than zero")]
+    InvalidTimeout { llm: String },
+    #[error("Fallback target '{llm}' in policy '{policy}' is not a known LLM")]
+    UnknownFallbackTarget { policy: String, llm: String },
+    #[error("Include cycle detected: {}", chain.join(" -> "))]
+    IncludeCycle { chain: Vec<String> },
+    #[error("Invalid URL for '{field}': '{value}' (expected an http or https URL)")]
+    InvalidUrl { field: String, value: String },
+    #[error("Duplicate policy name '{name}'")]
+    DuplicatePolicyName { name: String },
+    #[error("Duplicate LLM name '{name}' in policy '{policy}'")]
+    DuplicateLlmName { policy: String, name: String },
+    #[error("Unknown config profile '{profile}'")]
+    UnknownProfile { profile: String },
+    #[error("LLM '{llm}' must set exactly one of 'api_key' or 'api_key_file'")]
+    AmbiguousApiKey { llm: String },
+    #[error("Failed to read api_key_file '{path}' for LLM '{llm}': {source}")]
+    ApiKeyFileRead {
+        llm: String,
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Invalid provider config for LLM '{llm}': {message}")]
+    InvalidProviderConfig { llm: String, message: String },
+    #[error("Invalid health check for LLM '{llm}': {message}")]
+    InvalidHealthCheck { llm: String, message: String },
+    #[error("Invalid '{param}' for LLM '{llm}': {message}")]
+    InvalidParam {
+        llm: String,
+        param: String,
+        message: String,
+    },
+    #[error("Incompatible params for {provider} LLM '{llm}': {message}")]
+    IncompatibleParams {
+        llm: String,
+        provider: String,
+        message: String,
+    },
+    #[error("'{field}' for LLM '{llm}' must be a non-negative number")]
+    InvalidCost { llm: String, field: String },
+    #[error("Model alias cycle detected: {}", chain.join(" -> "))]
+    AliasCycle { chain: Vec<String> },
+    #[error("Invalid circuit breaker for LLM '{llm}': {message}")]
+    InvalidCircuitBreaker { llm: String, message: String },
+    #[error("Invalid rate limit for policy '{policy}': {message}")]
+    InvalidRateLimit { policy: String, message: String },
+    #[error("Policy '{policy}' inherits from unknown policy '{base}'")]
+    UnknownBasePolicy { policy: String, base: String },
+    #[error("Policy inheritance cycle detected: {}", chain.join(" -> "))]
+    PolicyInheritanceCycle { chain: Vec<String> },
+    #[error("Failed to resolve secret '{key}': {message}")]
+    UnresolvedSecret { key: String, message: String },
+    #[error("Default policy '{policy}' is not a known policy")]
+    UnknownDefaultPolicy { policy: String },
+    #[error("Host '{host}' of LLM '{llm}' is not in allowed_hosts")]
+    DisallowedHost { llm: String, host: String },
+    #[error("Invalid HTTP header '{name}' for LLM '{llm}'")]
+    InvalidHeader { llm: String, name: String },
+    #[error("Invalid HTTP header '{name}' for policy '{policy}'")]
+    InvalidPolicyHeader { policy: String, name: String },
+    #[error("Backoff for LLM '{llm}' has base_ms {base_ms} above max_ms {max_ms}")]
+    InvalidBackoff {
+        llm: String,
+        base_ms: u64,
+        max_ms: u64,
+    },
+    #[error("Invalid model_pattern '{pattern}' in policy '{policy}': {message}")]
+    InvalidModelPattern {
+        policy: String,
+        pattern: String,
+        message: String,
+    },
+    #[error(
+        "'{field}' for LLM '{llm}' must be between 1 and {}",
+        tokio::sync::Semaphore::MAX_PERMITS
+    )]
+    InvalidConcurrency { llm: String, field: String },
+    #[error(
+        "Model '{model}' is routed to policy '{policy}', but none of its enabled LLMs serve it"
+    )]
+    UnroutableModel { policy: String, model: String },
+    #[error("API key for LLM '{llm}' is an unresolved environment variable placeholder")]
+    UnresolvedApiKey { llm: String },
+    #[error("Unexpected environment variable placeholder in '{field}'")]
+    UnexpectedPlaceholder { field: String },
+    #[error("Routing graph cycle detected: {}", path.join(" -> "))]
+    RoutingGraphCycle { path: Vec<String> },
+    #[error("LLM '{llm}' in policy '{policy}' can never be selected")]
+    UnreachableLlm { policy: String, llm: String },
+    #[error("'{field}' for policy '{policy}' must be greater than zero")]
+    InvalidRequestLimit { policy: String, field: String },
+    #[error(
+        "Maintenance status code {status_code} for policy '{policy}' must be between 100 and 599"
+    )]
+    InvalidMaintenanceStatus { policy: String, status_code: u16 },
+    #[error("Adaptive selector decay {decay} for policy '{policy}' must be between 0 and 1")]
+    InvalidDecay { policy: String, decay: f64 },
+    #[error("Unknown config field '{path}'")]
+    UnknownField { path: String, field: String },
+    #[error("Unknown policy '{policy}'")]
+    UnknownPolicy { policy: String },
+    #[error("Unknown LLM '{llm}' in policy '{policy}'")]
+    UnknownLlm { policy: String, llm: String },
+    #[error("Cannot edit '{field}' in place: {message}")]
+    UneditableField { field: String, message: String },
+    #[error("Fetching config from '{url}' failed with HTTP status {status}")]
+    RemoteFetchFailed { url: String, status: u16 },
+    #[error("Unsupported config format '{extension}'")]
+    UnsupportedFormat { extension: String },
+    #[error("Invalid base64 API key for LLM '{llm}': {message}")]
+    InvalidSecretEncoding { llm: String, message: String },
+    #[error("Config is not valid UTF-8: invalid byte at offset {offset}")]
+    InvalidEncoding { offset: usize },
+    #[error("Config version {found} is newer than the supported version {max_supported}")]
+    UnsupportedConfigVersion { found: u64, max_supported: u32 },
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error(transparent)]
     Yaml(#[from] serde_yaml::Error),
+    #[error(transparent)]
+    Toml(#[from] toml::de::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -126,6 +258,83 @@ pub enum RoutingErrorType {
     TritonUnavailable,
 }
 
+impl RoutingErrorType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PolicyNotFound => "policy_not_found",
+            Self::ModelNotFound => "model_not_found",
+            Self::NoRoutingStrategy => "no_routing_strategy",
+            Self::InvalidConfiguration => "invalid_configuration",
+            Self::TritonUnavailable => "triton_unavailable",
+        }
+    }
+}
+
+impl ConfigError {
+    /// Stable machine-readable code for API error bodies. Codes are part of
+    /// the public contract: never change or reuse one, only add new ones.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::MissingPolicyField { .. } => "MISSING_POLICY_FIELD",
+            Self::MissingLlmField { .. } => "MISSING_LLM_FIELD",
+            Self::MissingEnvVar { .. } => "MISSING_ENV_VAR",
+            Self::UnresolvedEnvVars { .. } => "UNRESOLVED_ENV_VARS",
+            Self::EmptyPolicy { .. } => "EMPTY_POLICY",
+            Self::NoEnabledLlms { .. } => "NO_ENABLED_LLMS",
+            Self::InvalidWeights { .. } => "INVALID_WEIGHTS",
+            Self::InvalidTimeout { .. } => "INVALID_TIMEOUT",
+            Self::UnknownFallbackTarget { .. } => "UNKNOWN_FALLBACK_TARGET",
+            Self::IncludeCycle { .. } => "INCLUDE_CYCLE",
+            Self::InvalidUrl { .. } => "INVALID_URL",
+            Self::DuplicatePolicyName { .. } => "DUPLICATE_POLICY_NAME",
+            Self::DuplicateLlmName { .. } => "DUPLICATE_LLM_NAME",
+            Self::UnknownProfile { .. } => "UNKNOWN_PROFILE",
+            Self::AmbiguousApiKey { .. } => "AMBIGUOUS_API_KEY",
+            Self::ApiKeyFileRead { .. } => "API_KEY_FILE_READ",
+            Self::InvalidProviderConfig { .. } => "INVALID_PROVIDER_CONFIG",
+            Self::InvalidHealthCheck { .. } => "INVALID_HEALTH_CHECK",
+            Self::InvalidParam { .. } => "INVALID_PARAM",
+            Self::IncompatibleParams { .. } => "INCOMPATIBLE_PARAMS",
+            Self::InvalidCost { .. } => "INVALID_COST",
+            Self::AliasCycle { .. } => "ALIAS_CYCLE",
+            Self::InvalidCircuitBreaker { .. } => "INVALID_CIRCUIT_BREAKER",
+            Self::InvalidRateLimit { .. } => "INVALID_RATE_LIMIT",
+            Self::UnknownBasePolicy { .. } => "UNKNOWN_BASE_POLICY",
+            Self::PolicyInheritanceCycle { .. } => "POLICY_INHERITANCE_CYCLE",
+            Self::UnresolvedSecret { .. } => "UNRESOLVED_SECRET",
+            Self::UnknownDefaultPolicy { .. } => "UNKNOWN_DEFAULT_POLICY",
+            Self::DisallowedHost { .. } => "DISALLOWED_HOST",
+            Self::InvalidHeader { .. } => "INVALID_HEADER",
+            Self::InvalidPolicyHeader { .. } => "INVALID_POLICY_HEADER",
+            Self::InvalidBackoff { .. } => "INVALID_BACKOFF",
+            Self::InvalidModelPattern { .. } => "INVALID_MODEL_PATTERN",
+            Self::InvalidConcurrency { .. } => "INVALID_CONCURRENCY",
+            Self::UnroutableModel { .. } => "UNROUTABLE_MODEL",
+            Self::RoutingGraphCycle { .. } => "ROUTING_GRAPH_CYCLE",
+            Self::UnreachableLlm { .. } => "UNREACHABLE_LLM",
+            Self::InvalidRequestLimit { .. } => "INVALID_REQUEST_LIMIT",
+            Self::InvalidMaintenanceStatus { .. } => "INVALID_MAINTENANCE_STATUS",
+            Self::InvalidDecay { .. } => "INVALID_DECAY",
+            Self::UnknownField { .. } => "UNKNOWN_FIELD",
+            Self::UnknownPolicy { .. } => "UNKNOWN_POLICY",
+            Self::UnknownLlm { .. } => "UNKNOWN_LLM",
+            Self::UneditableField { .. } => "UNEDITABLE_FIELD",
+            Self::RemoteFetchFailed { .. } => "REMOTE_FETCH_FAILED",
+            Self::UnsupportedFormat { .. } => "UNSUPPORTED_FORMAT",
+            Self::InvalidSecretEncoding { .. } => "INVALID_SECRET_ENCODING",
+            Self::UnresolvedApiKey { .. } => "UNRESOLVED_API_KEY",
+            Self::UnexpectedPlaceholder { .. } => "UNEXPECTED_PLACEHOLDER",
+            Self::InvalidEncoding { .. } => "INVALID_ENCODING",
+            Self::UnsupportedConfigVersion { .. } => "UNSUPPORTED_CONFIG_VERSION",
+            Self::Io(_) => "IO_ERROR",
+            Self::Yaml(_) => "YAML_PARSE_ERROR",
+            Self::Toml(_) => "TOML_PARSE_ERROR",
+            Self::Json(_) => "JSON_PARSE_ERROR",
+            Self::Http(_) => "HTTP_ERROR",
+        }
+    }
+}
+
 impl GatewayApiError {
     pub fn error_source(&self) -> ErrorSource {
         match self {
@@ -190,7 +399,7 @@ impl GatewayApiError {
                 error_type,
             } => json!({
                 "error": {
-                    "type": format!("routing_error_{:?}", error_type).to_lowercase(),
+                    "type": format!("routing_error_{}", error_type.as_str()),
                     "message": message,
                     "status": self.status_code().as_u16(),
                     "source": "router"
@@ -419,4 +628,222 @@ mod tests {
         assert_eq!(json["error"]["type"], "validation_error");
         assert_eq!(json["error"]["source"], "client");
     }
+
+    fn all_config_errors() -> Vec<ConfigError> {
+        let s = |value: &str| value.to_string();
+        vec![
+            ConfigError::MissingPolicyField {
+                policy: s("p"),
+                field: s("url"),
+            },
+            ConfigError::MissingLlmField {
+                llm: s("l"),
+                field: s("model"),
+            },
+            ConfigError::MissingEnvVar {
+                var: s("KEY"),
+                message: s("unset"),
+            },
+            ConfigError::UnresolvedEnvVars {
+                vars: vec![s("KEY")],
+            },
+            ConfigError::EmptyPolicy { policy: s("p") },
+            ConfigError::NoEnabledLlms { policy: s("p") },
+            ConfigError::InvalidWeights { policy: s("p") },
+            ConfigError::InvalidTimeout { llm: s("l") },
+            ConfigError::UnknownFallbackTarget {
+                policy: s("p"),
+                llm: s("l"),
+            },
+            ConfigError::IncludeCycle {
+                chain: vec![s("a"), s("a")],
+            },
+            ConfigError::InvalidUrl {
+                field: s("api_base"),
+                value: s("x"),
+            },
+            ConfigError::DuplicatePolicyName { name: s("p") },
+            ConfigError::DuplicateLlmName {
+                policy: s("p"),
+                name: s("l"),
+            },
+            ConfigError::UnknownProfile { profile: s("prod") },
+            ConfigError::AmbiguousApiKey { llm: s("l") },
+            ConfigError::ApiKeyFileRead {
+                llm: s("l"),
+                path: s("/key"),
+                source: std::io::Error::from(std::io::ErrorKind::NotFound),
+            },
+            ConfigError::InvalidProviderConfig {
+                llm: s("l"),
+                message: s("m"),
+            },
+            ConfigError::InvalidHealthCheck {
+                llm: s("l"),
+                message: s("m"),
+            },
+            ConfigError::IncompatibleParams {
+                llm: s("l"),
+                provider: s("anthropic"),
+                message: s("m"),
+            },
+            ConfigError::InvalidParam {
+                llm: s("l"),
+                param: s("top_p"),
+                message: s("m"),
+            },
+            ConfigError::InvalidCost {
+                llm: s("l"),
+                field: s("input_cost_per_1k"),
+            },
+            ConfigError::AliasCycle {
+                chain: vec![s("a"), s("a")],
+            },
+            ConfigError::InvalidRateLimit {
+                policy: s("p"),
+                message: s("m"),
+            },
+            ConfigError::UnknownBasePolicy {
+                policy: s("p"),
+                base: s("b"),
+            },
+            ConfigError::PolicyInheritanceCycle {
+                chain: vec![s("a"), s("a")],
+            },
+            ConfigError::UnresolvedSecret {
+                key: s("k"),
+                message: s("m"),
+            },
+            ConfigError::UnknownDefaultPolicy { policy: s("p") },
+            ConfigError::DisallowedHost {
+                llm: s("l"),
+                host: s("h"),
+            },
+            ConfigError::InvalidHeader {
+                llm: s("l"),
+                name: s("x"),
+            },
+            ConfigError::InvalidPolicyHeader {
+                policy: s("p"),
+                name: s("x"),
+            },
+            ConfigError::InvalidBackoff {
+                llm: s("l"),
+                base_ms: 2,
+                max_ms: 1,
+            },
+            ConfigError::InvalidModelPattern {
+                policy: s("p"),
+                pattern: s("("),
+                message: s("m"),
+            },
+            ConfigError::InvalidConcurrency {
+                llm: s("l"),
+                field: s("max_connections"),
+            },
+            ConfigError::UnroutableModel {
+                policy: s("p"),
+                model: s("m"),
+            },
+            ConfigError::InvalidMaintenanceStatus {
+                policy: s("p"),
+                status_code: 42,
+            },
+            ConfigError::InvalidDecay {
+                policy: s("p"),
+                decay: 1.5,
+            },
+            ConfigError::UnknownField {
+                path: s("policies[0].nmae"),
+                field: s("nmae"),
+            },
+            ConfigError::UnknownPolicy { policy: s("p") },
+            ConfigError::UnknownLlm {
+                policy: s("p"),
+                llm: s("l"),
+            },
+            ConfigError::UneditableField {
+                field: s("p.l.model"),
+                message: s("m"),
+            },
+            ConfigError::RemoteFetchFailed {
+                url: s("http://config"),
+                status: 404,
+            },
+            ConfigError::UnsupportedFormat {
+                extension: s("ini"),
+            },
+            ConfigError::InvalidSecretEncoding {
+                llm: s("l"),
+                message: s("m"),
+            },
+            ConfigError::UnresolvedApiKey { llm: s("l") },
+            ConfigError::UnexpectedPlaceholder {
+                field: s("policies.0.name"),
+            },
+            ConfigError::InvalidEncoding { offset: 3 },
+            ConfigError::UnsupportedConfigVersion {
+                found: 2,
+                max_supported: 1,
+            },
+            ConfigError::RoutingGraphCycle {
+                path: vec![s("p/a"), s("p/b"), s("p/a")],
+            },
+            ConfigError::UnreachableLlm {
+                policy: s("p"),
+                llm: s("l"),
+            },
+            ConfigError::InvalidRequestLimit {
+                policy: s("p"),
+                field: s("max_tokens_limit"),
+            },
+            ConfigError::InvalidCircuitBreaker {
+                llm: s("l"),
+                message: s("m"),
+            },
+            ConfigError::Io(std::io::Error::from(std::io::ErrorKind::NotFound)),
+            ConfigError::Yaml(serde_yaml::from_str::<Value>("[").unwrap_err()),
+            ConfigError::Toml(toml::from_str::<toml::Value>("=").unwrap_err()),
+            ConfigError::Json(serde_json::from_str::<Value>("{").unwrap_err()),
+            ConfigError::Http(reqwest::Client::new().get("not a url").build().unwrap_err()),
+        ]
+    }
+
+    #[test]
+    fn test_config_error_codes_are_distinct() {
+        let errors = all_config_errors();
+        let codes: std::collections::HashSet<_> = errors.iter().map(ConfigError::code).collect();
+        assert_eq!(codes.len(), errors.len());
+        for code in codes {
+            assert!(code.chars().all(|c| c.is_ascii_uppercase() || c == '_'));
+        }
+        assert_eq!(
+            ConfigError::MissingLlmField {
+                llm: "l".to_string(),
+                field: "model".to_string()
+            }
+            .code(),
+            "MISSING_LLM_FIELD"
+        );
+    }
+
+    #[test]
+    fn test_config_error_display_includes_context() {
+        let error = ConfigError::DuplicateLlmName {
+            policy: "task_router".to_string(),
+            name: "Brainstorming".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Duplicate LLM name 'Brainstorming' in policy 'task_router'"
+        );
+
+        let error = ConfigError::ApiKeyFileRead {
+            llm: "Brainstorming".to_string(),
+            path: "/run/secrets/key".to_string(),
+            source: std::io::Error::from(std::io::ErrorKind::NotFound),
+        };
+        assert!(error.to_string().contains("'/run/secrets/key'"));
+        assert!(std::error::Error::source(&error).is_some());
+    }
 }
@@ -0,0 +1,213 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Explain
+use crate::config::{Llm, Policy};
+use crate::selector::PolicyCursor;
+use rand::Rng;
+use serde::Serialize;
+
+/// How an LLM was selected.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PickStrategy {
+    /// `Policy::pick_weighted_llm`
+    Weighted,
+    /// `PolicyCursor::next_llm`
+    RoundRobin,
+    /// `Policy::tiered_pick`
+    Tier,
+}
+
+/// Why an LLM was left out of a pick.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExclusionReason {
+    Disabled,
+    ZeroWeight,
+    /// A lower tier had a candidate.
+    HigherTier,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ExcludedLlm {
+    pub name: String,
+    pub reason: ExclusionReason,
+}
+
+/// A record of one routing decision, for structured logs answering "why did
+/// my request go there?".
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PickExplanation {
+    pub policy: String,
+    pub strategy: PickStrategy,
+    /// LLMs that could have been chosen, in declaration order.
+    pub candidates: Vec<String>,
+    pub excluded: Vec<ExcludedLlm>,
+    /// `None` when there was no candidate.
+    pub chosen: Option<String>,
+}
+
+impl Policy {
+    /// Picks an LLM like `tiered_pick` when any LLM sets a `tier`, and like
+    /// `pick_weighted_llm` otherwise, explaining the decision. Given the same
+    /// `rng` state, the pick matches those methods.
+    pub fn explain_pick(&self, rng: &mut impl Rng) -> (Option<&Llm>, PickExplanation) {
+        let tiered = self.llms.iter().any(|llm| llm.tier.is_some());
+        let tier = self
+            .enabled_llms()
+            .into_iter()
+            .filter(|llm| llm.effective_weight() > 0)
+            .map(Llm::effective_tier)
+            .min();
+
+        let mut candidates = Vec::new();
+        let mut excluded = Vec::new();
+        for llm in &self.llms {
+            let reason = if !llm.is_enabled() {
+                Some(ExclusionReason::Disabled)
+            } else if llm.effective_weight() == 0 {
+                Some(ExclusionReason::ZeroWeight)
+            } else if tiered && Some(llm.effective_tier()) != tier {
+                Some(ExclusionReason::HigherTier)
+            } else {
+                None
+            };
+            match reason {
+                Some(reason) => excluded.push(ExcludedLlm {
+                    name: llm.name.clone(),
+                    reason,
+                }),
+                None => candidates.push(llm),
+            }
+        }
+
+        let (strategy, chosen) = if tiered {
+            (PickStrategy::Tier, self.tiered_pick(rng))
+        } else {
+            (PickStrategy::Weighted, self.pick_weighted_llm(rng))
+        };
+        let explanation = PickExplanation {
+            policy: self.name.clone(),
+            strategy,
+            candidates: candidates.iter().map(|llm| llm.name.clone()).collect(),
+            excluded,
+            chosen: chosen.map(|llm| llm.name.clone()),
+        };
+        (chosen, explanation)
+    }
+}
+
+impl PolicyCursor {
+    /// `next_llm`, explaining the decision like `Policy::explain_pick`.
+    pub fn explain_next<'a>(&self, policy: &'a Policy) -> (Option<&'a Llm>, PickExplanation) {
+        let chosen = self.next_llm(policy);
+        let (candidates, disabled): (Vec<&Llm>, Vec<&Llm>) =
+            policy.llms.iter().partition(|llm| llm.is_enabled());
+        let explanation = PickExplanation {
+            policy: policy.name.clone(),
+            strategy: PickStrategy::RoundRobin,
+            candidates: candidates.iter().map(|llm| llm.name.clone()).collect(),
+            excluded: disabled
+                .iter()
+                .map(|llm| ExcludedLlm {
+                    name: llm.name.clone(),
+                    reason: ExclusionReason::Disabled,
+                })
+                .collect(),
+            chosen: chosen.map(|llm| llm.name.clone()),
+        };
+        (chosen, explanation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{test_llm, test_policy};
+    use rand::SeedableRng;
+
+    fn llm(name: &str, weight: u32, tier: Option<u32>, enabled: bool) -> Llm {
+        Llm {
+            tier,
+            enabled: Some(enabled),
+            ..test_llm(name, Some(weight))
+        }
+    }
+
+    fn policy(llms: Vec<Llm>) -> Policy {
+        test_policy("task_router", llms)
+    }
+
+    #[test]
+    fn test_explain_pick_lists_disabled_llms() {
+        let policy = policy(vec![
+            llm("a", 3, None, true),
+            llm("off", 5, None, false),
+            llm("b", 1, None, true),
+            llm("drained", 0, None, true),
+        ]);
+        let (chosen, explanation) = policy.explain_pick(&mut rand::rngs::StdRng::seed_from_u64(7));
+
+        let expected = policy.pick_weighted_llm(&mut rand::rngs::StdRng::seed_from_u64(7));
+        assert_eq!(chosen, expected);
+        assert_eq!(explanation.strategy, PickStrategy::Weighted);
+        assert_eq!(explanation.candidates, vec!["a", "b"]);
+        assert_eq!(
+            explanation.excluded,
+            vec![
+                ExcludedLlm {
+                    name: "off".to_string(),
+                    reason: ExclusionReason::Disabled,
+                },
+                ExcludedLlm {
+                    name: "drained".to_string(),
+                    reason: ExclusionReason::ZeroWeight,
+                },
+            ]
+        );
+        assert_eq!(
+            explanation.chosen.as_deref(),
+            chosen.map(|llm| llm.name.as_str())
+        );
+
+        let json = serde_json::to_value(&explanation).unwrap();
+        assert_eq!(json["strategy"], "weighted");
+        assert_eq!(json["excluded"][0]["reason"], "disabled");
+    }
+
+    #[test]
+    fn test_explain_pick_tiers() {
+        let policy = policy(vec![
+            llm("primary", 1, Some(0), false),
+            llm("secondary", 1, Some(1), true),
+            llm("backup", 1, Some(2), true),
+        ]);
+        let (chosen, explanation) = policy.explain_pick(&mut rand::thread_rng());
+        assert_eq!(chosen.unwrap().name, "secondary");
+        assert_eq!(explanation.strategy, PickStrategy::Tier);
+        assert_eq!(explanation.candidates, vec!["secondary"]);
+        assert_eq!(explanation.excluded[1].reason, ExclusionReason::HigherTier);
+    }
+
+    #[test]
+    fn test_explain_next_round_robin() {
+        let policy = policy(vec![llm("a", 1, None, true), llm("off", 1, None, false)]);
+        let (chosen, explanation) = PolicyCursor::new().explain_next(&policy);
+        assert_eq!(chosen.unwrap().name, "a");
+        assert_eq!(explanation.strategy, PickStrategy::RoundRobin);
+        assert_eq!(explanation.excluded[0].name, "off");
+    }
+}
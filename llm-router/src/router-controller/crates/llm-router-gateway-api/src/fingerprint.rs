@@ -0,0 +1,141 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fingerprint
+use crate::config::RouterConfig;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+impl RouterConfig {
+    /// Hex SHA-256 of the config's content, for caching and change detection.
+    /// Map key order and the order of policies and of LLMs within a policy do
+    /// not affect it, and secrets are redacted first, so the fingerprint is safe
+    /// to log. Rotating an API key therefore leaves it unchanged.
+    pub fn fingerprint(&self) -> String {
+        let mut config = self.sanitized_full();
+        config
+            .policies
+            .sort_by(|a, b| a.name.trim().cmp(b.name.trim()));
+        for policy in &mut config.policies {
+            policy.llms.sort_by(|a, b| a.name.trim().cmp(b.name.trim()));
+        }
+        let value = serde_json::to_value(&config).expect("Serialization to JSON should succeed.");
+        let mut canonical = String::new();
+        write_canonical(&value, &mut canonical);
+        format!("{:x}", Sha256::digest(canonical.as_bytes()))
+    }
+}
+
+/// Writes `value` as compact JSON with object keys sorted.
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(item, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigFormat;
+
+    const CONFIG: &str = r#"
+policies:
+  - name: task_router
+    url: http://router-server:8000/v2/models/task_router_ensemble/infer
+    llms:
+      - name: Brainstorming
+        api_base: https://integrate.api.nvidia.com
+        api_key: key-one
+        model: meta/llama-3.1-70b-instruct
+      - name: Chatbot
+        api_base: https://integrate.api.nvidia.com
+        api_key: key-one
+        model: meta/llama-3.1-8b-instruct
+  - name: complexity_router
+    url: http://router-server:8000/v2/models/complexity_router_ensemble/infer
+    llms:
+      - name: Creativity
+        api_base: https://integrate.api.nvidia.com
+        api_key: key-one
+        model: meta/llama-3.1-70b-instruct
+"#;
+
+    const REORDERED: &str = r#"
+policies:
+  - url: http://router-server:8000/v2/models/complexity_router_ensemble/infer
+    name: complexity_router
+    llms:
+      - model: meta/llama-3.1-70b-instruct
+        name: Creativity
+        api_key: key-two
+        api_base: https://integrate.api.nvidia.com
+  - llms:
+      - name: Chatbot
+        model: meta/llama-3.1-8b-instruct
+        api_base: https://integrate.api.nvidia.com
+        api_key: key-two
+      - name: Brainstorming
+        model: meta/llama-3.1-70b-instruct
+        api_base: https://integrate.api.nvidia.com
+        api_key: key-two
+    name: task_router
+    url: http://router-server:8000/v2/models/task_router_ensemble/infer
+"#;
+
+    fn load(content: &str) -> RouterConfig {
+        RouterConfig::load_config_from_str(content, ConfigFormat::Yaml).unwrap()
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_ordering_and_secrets() {
+        let fingerprint = load(CONFIG).fingerprint();
+        assert_eq!(fingerprint.len(), 64);
+        assert_eq!(fingerprint, load(REORDERED).fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_model() {
+        let changed = CONFIG.replacen(
+            "meta/llama-3.1-8b-instruct",
+            "mistralai/mixtral-8x22b-instruct-v0.1",
+            1,
+        );
+        assert_ne!(load(CONFIG).fingerprint(), load(&changed).fingerprint());
+    }
+}
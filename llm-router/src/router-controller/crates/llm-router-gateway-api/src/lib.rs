@@ -15,9 +15,32 @@
 
 //! Lib
 
+pub mod builder;
+pub mod circuit_breaker;
 pub mod config;
+pub mod diff;
+pub mod edit;
+pub mod env_vars;
 pub mod error;
+pub mod explain;
+pub mod fingerprint;
+pub mod lint;
 pub mod metrics;
+pub mod models;
+pub mod openapi;
+pub mod params;
+pub mod probe;
 pub mod proxy;
+pub mod rate_limit;
+pub mod routing;
+pub mod secrets;
+pub mod selector;
+pub mod snapshot;
+pub mod source;
+pub mod stats;
 pub mod stream;
+pub mod template;
+#[cfg(test)]
+pub(crate) mod test_fixtures;
 pub mod triton;
+pub mod watch;
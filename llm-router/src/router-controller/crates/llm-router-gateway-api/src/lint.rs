@@ -0,0 +1,244 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lint
+use crate::config::{is_sensitive_name, Llm, RouterConfig, DEFAULT_TIMEOUT_MS};
+use crate::secrets::SECRET_PREFIX;
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+}
+
+/// Advisory finding from `RouterConfig::lint`. Unlike a `ConfigError` it
+/// never prevents a config from loading.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConfigWarning {
+    pub severity: Severity,
+    pub policy: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub llm: Option<String>,
+    pub message: String,
+}
+
+impl RouterConfig {
+    /// Checks of how enabled policies will route, which do not depend on how
+    /// secrets were loaded: gaps between LLM tiers and policies without an LLM
+    /// that supports streaming. The server logs them once at startup, and
+    /// `lint` includes them.
+    pub fn routing_warnings(&self) -> Vec<ConfigWarning> {
+        let mut warnings = Vec::new();
+        for policy in self.policies.iter().filter(|policy| policy.is_enabled()) {
+            let warn = |message: String| ConfigWarning {
+                severity: Severity::Warning,
+                policy: policy.name.clone(),
+                llm: None,
+                message,
+            };
+
+            let tiers: std::collections::BTreeSet<u32> =
+                policy.llms.iter().map(Llm::effective_tier).collect();
+            if tiers.iter().copied().ne(0..tiers.len() as u32) {
+                warnings.push(warn(format!(
+                    "Policy '{}' has non-contiguous LLM tiers: {:?}",
+                    policy.name, tiers
+                )));
+            }
+            let enabled = policy.enabled_llms();
+            if !enabled.is_empty() && !enabled.iter().any(|llm| llm.supports_streaming()) {
+                // Non-streaming requests can still be served
+                warnings.push(warn(format!(
+                    "Policy '{}' has no LLM that supports streaming",
+                    policy.name
+                )));
+            }
+        }
+        warnings
+    }
+
+    /// Advisory checks for a config that is valid but could be better:
+    /// hardcoded secrets, LLMs relying on the default timeout and policies
+    /// without a second LLM to fail over to, along with `routing_warnings`.
+    ///
+    /// Secrets are judged as they are held. Keys read from `api_key_file` are
+    /// never flagged, but loading substitutes `${VAR}` placeholders and
+    /// `secret://` references, so lint a config from `load_config_raw` to tell
+    /// keys taken from the environment apart from hardcoded ones.
+    pub fn lint(&self) -> Vec<ConfigWarning> {
+        let mut warnings = self.routing_warnings();
+        for policy in &self.policies {
+            let warn = |severity, llm: Option<&str>, message: String| ConfigWarning {
+                severity,
+                policy: policy.name.clone(),
+                llm: llm.map(str::to_string),
+                message,
+            };
+
+            if policy.enabled_llms().len() == 1 {
+                warnings.push(warn(
+                    Severity::Warning,
+                    None,
+                    format!(
+                        "Policy '{}' has a single enabled LLM and no failover",
+                        policy.name
+                    ),
+                ));
+            }
+
+            for (header, value) in policy.headers.iter().flatten() {
+                if is_sensitive_name(header) && is_hardcoded(value) {
+                    warnings.push(warn(
+                        Severity::Warning,
+                        None,
+                        format!(
+                            "Header '{}' of policy '{}' is hardcoded, consider an environment variable",
+                            header, policy.name
+                        ),
+                    ));
+                }
+            }
+
+            for llm in &policy.llms {
+                let name = Some(llm.name.as_str());
+                if llm.api_key_file.is_none() && is_hardcoded(&llm.api_key) {
+                    warnings.push(warn(
+                        Severity::Warning,
+                        name,
+                        format!(
+                            "api_key of LLM '{}' is hardcoded, consider an environment variable, api_key_file or secret:// reference",
+                            llm.name
+                        ),
+                    ));
+                }
+                for (header, value) in llm.headers.iter().flatten() {
+                    if is_sensitive_name(header) && is_hardcoded(value) {
+                        warnings.push(warn(
+                            Severity::Warning,
+                            name,
+                            format!(
+                                "Header '{}' of LLM '{}' is hardcoded, consider an environment variable",
+                                header, llm.name
+                            ),
+                        ));
+                    }
+                }
+                if llm.timeout_ms.is_none() {
+                    warnings.push(warn(
+                        Severity::Info,
+                        name,
+                        format!(
+                            "LLM '{}' sets no timeout_ms, the default of {} ms applies",
+                            llm.name, DEFAULT_TIMEOUT_MS
+                        ),
+                    ));
+                }
+            }
+        }
+        warnings
+    }
+}
+
+fn is_hardcoded(value: &str) -> bool {
+    !value.is_empty() && !value.contains("${") && !value.starts_with(SECRET_PREFIX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const SLOPPY_CONFIG: &str = r#"
+policies:
+  - name: solo
+    url: http://router:8000/v2/models/solo/infer
+    llms:
+      - name: Hardcoded
+        api_base: https://integrate.api.nvidia.com
+        api_key: nvapi-1234567890
+        model: meta/llama-3.1-70b-instruct
+        headers:
+          X-Api-Key: literal-token
+          anthropic-version: "2023-06-01"
+  - name: tidy
+    url: http://router:8000/v2/models/tidy/infer
+    llms:
+      - name: FromEnv
+        api_base: https://integrate.api.nvidia.com
+        api_key: ${CONFIG_TEST_LINT_KEY}
+        model: meta/llama-3.1-70b-instruct
+        timeout_ms: 5000
+      - name: FromFile
+        api_base: https://integrate.api.nvidia.com
+        api_key_file: KEY_FILE
+        model: meta/llama-3.1-8b-instruct
+        timeout_ms: 5000
+"#;
+
+    /// The config file and the key file it references.
+    fn write_sloppy_config() -> (tempfile::NamedTempFile, tempfile::NamedTempFile) {
+        let mut key_file = tempfile::NamedTempFile::new().unwrap();
+        key_file.write_all(b"nvapi-from-file").unwrap();
+        let content = SLOPPY_CONFIG.replace("KEY_FILE", key_file.path().to_str().unwrap());
+
+        let mut file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        (file, key_file)
+    }
+
+    #[test]
+    fn test_lint_flags_sloppy_config() {
+        let (file, _key_file) = write_sloppy_config();
+        let config = RouterConfig::load_config_raw(file.path().to_str().unwrap()).unwrap();
+        let before = config.clone();
+
+        let warnings = config.lint();
+        let summary: Vec<_> = warnings
+            .iter()
+            .map(|warning| {
+                (
+                    warning.severity,
+                    warning.policy.as_str(),
+                    warning.llm.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (Severity::Warning, "solo", None),
+                (Severity::Warning, "solo", Some("Hardcoded")),
+                (Severity::Warning, "solo", Some("Hardcoded")),
+                (Severity::Info, "solo", Some("Hardcoded")),
+            ]
+        );
+        assert!(warnings[1]
+            .message
+            .contains("api_key of LLM 'Hardcoded' is hardcoded"));
+        assert!(warnings[2].message.contains("Header 'X-Api-Key'"));
+        assert!(warnings[3].message.contains("default of 60000 ms"));
+        assert_eq!(config, before);
+    }
+
+    #[test]
+    fn test_lint_clean_config() {
+        let (file, _key_file) = write_sloppy_config();
+        let mut config = RouterConfig::load_config_raw(file.path().to_str().unwrap()).unwrap();
+        config.policies.retain(|policy| policy.name == "tidy");
+        assert!(config.lint().is_empty());
+    }
+}
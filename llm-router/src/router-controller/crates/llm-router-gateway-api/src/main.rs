@@ -14,13 +14,12 @@
 // limitations under the License.
 
 //! Main
-use clap::{arg, command, Parser};
-use env_logger;
+use clap::Parser;
 use hyper::service::service_fn;
 use hyper_util::rt::{TokioExecutor, TokioIo};
 use llm_router_gateway_api::config::RouterConfig;
 use llm_router_gateway_api::proxy::handler;
-use log::{error, info};
+use log::{error, info, warn};
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
 
@@ -29,6 +28,9 @@ use tokio::net::TcpListener;
 struct Args {
     #[arg(long)]
     config_path: String,
+    /// Refuse to start if an API key placeholder is unresolved
+    #[arg(long)]
+    strict: bool,
 }
 
 #[tokio::main]
@@ -37,13 +39,22 @@ async fn main() -> anyhow::Result<()> {
     // cargo run -- --config foobar
     info!("Gateway API is active and running.");
     let args = Args::parse();
-    let config = match RouterConfig::load_config(&args.config_path) {
+    let loaded = RouterConfig::load_config_lenient(&args.config_path).and_then(|config| {
+        if args.strict {
+            config.validate_strict()?;
+        }
+        Ok(config)
+    });
+    let config = match loaded {
         Ok(config) => config,
         Err(e) => {
             error!("Failed to load configuration: {}", e);
             return Err(e.into());
         }
     };
+    for warning in config.routing_warnings() {
+        warn!("{}", warning.message);
+    }
     let addr = SocketAddr::from(([0, 0, 0, 0], 8084));
     let listener = TcpListener::bind(addr).await?;
     info!("Listening on http://{}", addr);
@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Models
+use crate::config::{Provider, RouterConfig};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+impl Provider {
+    /// Value of `owned_by` in OpenAI model listings.
+    pub fn owner(&self) -> &'static str {
+        match self {
+            Provider::OpenAI => "openai",
+            Provider::Anthropic => "anthropic",
+            Provider::AzureOpenAI => "azure_openai",
+            Provider::Ollama => "ollama",
+            Provider::Custom => "custom",
+        }
+    }
+}
+
+impl RouterConfig {
+    /// OpenAI `/v1/models` response listing every distinct `model` of the
+    /// enabled LLMs in enabled policies, in config order. A model served by
+    /// several LLMs is listed once, owned by the provider of the first.
+    pub fn to_model_list(&self) -> Value {
+        let mut seen = HashSet::new();
+        let data: Vec<Value> = self
+            .all_llms()
+            .filter(|(_, llm)| seen.insert(llm.model.as_str()))
+            .map(|(_, llm)| {
+                json!({
+                    "id": llm.model,
+                    "object": "model",
+                    "created": 0,
+                    "owned_by": llm.provider_or_default().owner(),
+                })
+            })
+            .collect();
+        json!({ "object": "list", "data": data })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Llm;
+    use crate::test_fixtures::{test_config, test_llm, test_policy};
+
+    fn llm(name: &str, model: &str, provider: Option<Provider>) -> Llm {
+        Llm {
+            model: model.to_string(),
+            provider,
+            ..test_llm(name, None)
+        }
+    }
+
+    #[test]
+    fn test_model_list_shape_and_dedup() {
+        let mut disabled = llm("off", "never/listed", None);
+        disabled.enabled = Some(false);
+        let config = test_config(vec![
+            test_policy(
+                "task_router",
+                vec![
+                    llm("a", "meta/llama-3.1-70b-instruct", None),
+                    llm("b", "claude-3-5-sonnet", Some(Provider::Anthropic)),
+                    disabled,
+                ],
+            ),
+            test_policy(
+                "complexity_router",
+                vec![llm(
+                    "c",
+                    "meta/llama-3.1-70b-instruct",
+                    Some(Provider::Ollama),
+                )],
+            ),
+        ]);
+
+        assert_eq!(
+            config.to_model_list(),
+            json!({
+                "object": "list",
+                "data": [
+                    {
+                        "id": "meta/llama-3.1-70b-instruct",
+                        "object": "model",
+                        "created": 0,
+                        "owned_by": "openai",
+                    },
+                    {
+                        "id": "claude-3-5-sonnet",
+                        "object": "model",
+                        "created": 0,
+                        "owned_by": "anthropic",
+                    },
+                ],
+            })
+        );
+    }
+}
@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! OpenAPI
+use crate::config::RouterConfig;
+use crate::stats::ConfigStats;
+use schemars::gen::SchemaSettings;
+use serde_json::{json, Value};
+
+/// Dialect of the component schemas, the OpenAPI 3.1 default.
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl RouterConfig {
+    /// OpenAPI 3.1 document for the admin endpoints served next to the proxy:
+    /// `/config`, `/stats`, `/v1/models`, `/health` and `/metrics`. Response
+    /// schemas are generated from the config types, so the document follows
+    /// them as fields are added. The proxied completion endpoints are not
+    /// described, as their bodies belong to the backends.
+    pub fn admin_openapi() -> Value {
+        // schemars has no 2020-12 preset. Its 2019-09 output only differs for
+        // tuples, which the config types do not use, and the test checks every
+        // component against the 2020-12 metaschema.
+        let mut generator = SchemaSettings::draft2019_09()
+            .with(|settings| {
+                settings.definitions_path = "#/components/schemas/".to_string();
+                settings.meta_schema = Some(JSON_SCHEMA_DIALECT.to_string());
+            })
+            .into_generator();
+        let config = generator.subschema_for::<RouterConfig>();
+        let stats = generator.subschema_for::<ConfigStats>();
+        let mut schemas = serde_json::to_value(generator.take_definitions())
+            .expect("Serialization to JSON should succeed.");
+        schemas["ModelList"] = json!({
+            "type": "object",
+            "required": ["object", "data"],
+            "properties": {
+                "object": { "const": "list" },
+                "data": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["id", "object", "created", "owned_by"],
+                        "properties": {
+                            "id": { "type": "string" },
+                            "object": { "const": "model" },
+                            "created": { "type": "integer" },
+                            "owned_by": { "type": "string" },
+                        },
+                    },
+                },
+            },
+        });
+        schemas["Health"] = json!({
+            "type": "object",
+            "required": ["status"],
+            "properties": { "status": { "const": "OK" } },
+        });
+
+        let get = |summary: &str, description: &str, content: Value| {
+            json!({
+                "get": {
+                    "summary": summary,
+                    "responses": {
+                        "200": { "description": description, "content": content },
+                    },
+                },
+            })
+        };
+        let json_body = |schema: Value| json!({ "application/json": { "schema": schema } });
+        let reference = |name: &str| json!({ "$ref": format!("#/components/schemas/{}", name) });
+
+        json!({
+            "openapi": "3.1.0",
+            "info": {
+                "title": "LLM Router Gateway Admin API",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "jsonSchemaDialect": JSON_SCHEMA_DIALECT,
+            "paths": {
+                "/config": get(
+                    "Current configuration",
+                    "The loaded config with secrets and URL credentials redacted",
+                    json_body(serde_json::to_value(config).expect("Serialization to JSON should succeed.")),
+                ),
+                "/stats": get(
+                    "Configuration summary",
+                    "Counts of policies, LLMs and providers",
+                    json_body(serde_json::to_value(stats).expect("Serialization to JSON should succeed.")),
+                ),
+                "/v1/models": get(
+                    "Served models",
+                    "Distinct models of the enabled LLMs, in the OpenAI model list format",
+                    json_body(reference("ModelList")),
+                ),
+                "/health": get("Health check", "The gateway is up", json_body(reference("Health"))),
+                "/metrics": get(
+                    "Prometheus metrics",
+                    "Metrics in the Prometheus text format",
+                    json!({ "text/plain": { "schema": { "type": "string" } } }),
+                ),
+            },
+            "components": { "schemas": schemas },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The official OpenAPI 3.1 schema, from
+    /// https://spec.openapis.org/oas/3.1/schema/2022-10-07.
+    const OPENAPI_SCHEMA: &str = include_str!("../tests/fixtures/openapi-3.1-schema.json");
+
+    /// Validates `instance` against the response schema of `path`, resolving
+    /// references into the document's components.
+    fn response_is_valid(document: &Value, path: &str, instance: &Value) -> bool {
+        let mut schema = document["paths"][path]["get"]["responses"]["200"]["content"]
+            ["application/json"]["schema"]
+            .clone();
+        schema["$schema"] = json!(JSON_SCHEMA_DIALECT);
+        schema["components"] = document["components"].clone();
+        jsonschema::validator_for(&schema)
+            .unwrap()
+            .is_valid(instance)
+    }
+
+    #[test]
+    fn test_admin_openapi_is_valid() {
+        let document = RouterConfig::admin_openapi();
+        let meta: Value = serde_json::from_str(OPENAPI_SCHEMA).unwrap();
+        let validator = jsonschema::validator_for(&meta).unwrap();
+        let errors: Vec<String> = validator
+            .iter_errors(&document)
+            .map(|error| error.to_string())
+            .collect();
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        // The OpenAPI schema accepts any object as a schema, so each one is
+        // checked against the declared dialect as well
+        assert_eq!(document["jsonSchemaDialect"], JSON_SCHEMA_DIALECT);
+        for (name, schema) in document["components"]["schemas"].as_object().unwrap() {
+            assert!(jsonschema::draft202012::meta::is_valid(schema), "{}", name);
+        }
+
+        let mut invalid = document.clone();
+        invalid["paths"]["/health"]["get"]["responses"] = json!({});
+        assert!(!validator.is_valid(&invalid));
+
+        let paths: Vec<&String> = document["paths"].as_object().unwrap().keys().collect();
+        assert_eq!(
+            paths,
+            ["/config", "/health", "/metrics", "/stats", "/v1/models"]
+        );
+    }
+
+    #[test]
+    fn test_admin_openapi_describes_responses() {
+        std::env::set_var("NVIDIA_API_KEY", "test-key");
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../config.yaml");
+        let config = RouterConfig::load_config(path).unwrap();
+        let document = RouterConfig::admin_openapi();
+
+        let sanitized = serde_json::to_value(config.sanitized_full()).unwrap();
+        assert!(response_is_valid(&document, "/config", &sanitized));
+        let stats = serde_json::to_value(config.stats()).unwrap();
+        assert!(response_is_valid(&document, "/stats", &stats));
+        assert!(response_is_valid(
+            &document,
+            "/v1/models",
+            &config.to_model_list()
+        ));
+        assert!(response_is_valid(
+            &document,
+            "/health",
+            &json!({ "status": "OK" })
+        ));
+
+        assert!(!response_is_valid(
+            &document,
+            "/stats",
+            &json!({ "policy_count": "two" })
+        ));
+    }
+}
@@ -0,0 +1,193 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Params
+use crate::config::{Llm, LlmParams, Provider, Result};
+use crate::error::ConfigError;
+
+/// A constraint a provider places on generation parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ParamRule {
+    /// At most one of these may be set.
+    Exclusive(&'static [&'static str]),
+    /// A numeric parameter must lie within `min..=max`.
+    Range {
+        param: &'static str,
+        min: f64,
+        max: f64,
+    },
+    /// The parameter is rejected by the provider's API.
+    Unsupported(&'static str),
+}
+
+/// Parameter rules per provider. Providers without entries accept anything
+/// the generic checks allow.
+const PROVIDER_PARAM_RULES: &[(Provider, ParamRule)] = &[
+    (
+        Provider::Anthropic,
+        ParamRule::Exclusive(&["temperature", "top_p"]),
+    ),
+    (
+        Provider::Anthropic,
+        ParamRule::Range {
+            param: "temperature",
+            min: 0.0,
+            max: 1.0,
+        },
+    ),
+    (
+        Provider::Anthropic,
+        ParamRule::Unsupported("frequency_penalty"),
+    ),
+    (
+        Provider::Anthropic,
+        ParamRule::Unsupported("presence_penalty"),
+    ),
+    (Provider::Anthropic, ParamRule::Unsupported("logit_bias")),
+    (Provider::Anthropic, ParamRule::Unsupported("n")),
+];
+
+impl Llm {
+    /// Checks `params` against the rules of this LLM's `provider`, e.g. that
+    /// Anthropic is not sent both `temperature` and `top_p`. Run it on
+    /// `Llm::merged_params` to reject a request before it reaches the backend.
+    /// Fails with `ConfigError::IncompatibleParams` on the first broken rule.
+    pub fn validate_params(&self, params: &LlmParams) -> Result<()> {
+        let provider = self.provider_or_default();
+        let incompatible = |message: String| ConfigError::IncompatibleParams {
+            llm: self.name.clone(),
+            provider: provider.owner().to_string(),
+            message,
+        };
+        let rules = PROVIDER_PARAM_RULES
+            .iter()
+            .filter(|(rule_provider, _)| *rule_provider == provider)
+            .map(|(_, rule)| rule);
+        for rule in rules {
+            match *rule {
+                ParamRule::Exclusive(names) => {
+                    let set: Vec<&str> = names
+                        .iter()
+                        .copied()
+                        .filter(|name| is_set(params, name))
+                        .collect();
+                    if set.len() > 1 {
+                        return Err(incompatible(format!(
+                            "{} cannot be set together",
+                            set.join(" and ")
+                        )));
+                    }
+                }
+                ParamRule::Range { param, min, max } => {
+                    if let Some(value) = number(params, param) {
+                        if !(min..=max).contains(&value) {
+                            return Err(incompatible(format!(
+                                "{} {} is outside {:?}..={:?}",
+                                param, value, min, max
+                            )));
+                        }
+                    }
+                }
+                ParamRule::Unsupported(param) => {
+                    if is_set(params, param) {
+                        return Err(incompatible(format!("{} is not supported", param)));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_set(params: &LlmParams, name: &str) -> bool {
+    match name {
+        "temperature" => params.temperature.is_some(),
+        "top_p" => params.top_p.is_some(),
+        "max_tokens" => params.max_tokens.is_some(),
+        _ => params.extra.get(name).is_some_and(|value| !value.is_null()),
+    }
+}
+
+fn number(params: &LlmParams, name: &str) -> Option<f64> {
+    match name {
+        "temperature" => params.temperature,
+        "top_p" => params.top_p,
+        "max_tokens" => params.max_tokens.map(f64::from),
+        _ => params.extra.get(name).and_then(serde_json::Value::as_f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::test_llm;
+    use std::collections::HashMap;
+
+    fn llm(provider: Provider) -> Llm {
+        Llm {
+            provider: Some(provider),
+            ..test_llm("a", None)
+        }
+    }
+
+    fn sampling(temperature: f64, top_p: f64) -> LlmParams {
+        LlmParams {
+            temperature: Some(temperature),
+            top_p: Some(top_p),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_openai_accepts_temperature_with_top_p() {
+        let params = LlmParams {
+            extra: HashMap::from([("frequency_penalty".to_string(), serde_json::json!(0.5))]),
+            ..sampling(1.5, 0.9)
+        };
+        assert!(llm(Provider::OpenAI).validate_params(&params).is_ok());
+    }
+
+    #[test]
+    fn test_anthropic_rejects_incompatible_params() {
+        let anthropic = llm(Provider::Anthropic);
+        assert!(matches!(
+            anthropic.validate_params(&sampling(0.7, 0.9)),
+            Err(ConfigError::IncompatibleParams { llm, provider, message })
+                if llm == "a" && provider == "anthropic" && message == "temperature and top_p cannot be set together"
+        ));
+
+        let too_hot = LlmParams {
+            temperature: Some(1.5),
+            ..Default::default()
+        };
+        assert!(matches!(
+            anthropic.validate_params(&too_hot),
+            Err(ConfigError::IncompatibleParams { message, .. }) if message.contains("outside 0.0..=1.0")
+        ));
+
+        let penalized = LlmParams {
+            extra: HashMap::from([("presence_penalty".to_string(), serde_json::json!(0.1))]),
+            ..Default::default()
+        };
+        assert!(anthropic.validate_params(&penalized).is_err());
+
+        let top_p_only = LlmParams {
+            top_p: Some(0.9),
+            max_tokens: Some(1024),
+            ..Default::default()
+        };
+        assert!(anthropic.validate_params(&top_p_only).is_ok());
+    }
+}
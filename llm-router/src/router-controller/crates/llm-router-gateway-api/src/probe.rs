@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Probe
+use crate::config::{Llm, Policy, RouterConfig};
+use futures_util::future::join_all;
+use std::time::{Duration, Instant};
+
+/// Upper bound for a single backend probe, including connecting.
+pub const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Outcome of probing one LLM backend.
+#[derive(Debug, Clone)]
+pub struct ProbeResult {
+    pub policy: String,
+    pub llm: String,
+    pub url: String,
+    /// HTTP status, if the backend answered at all.
+    pub status: Option<u16>,
+    pub latency: Duration,
+    /// Connection, TLS or timeout error, if the request failed.
+    pub error: Option<String>,
+}
+
+impl ProbeResult {
+    /// True if the backend answered with a 2xx status.
+    pub fn is_healthy(&self) -> bool {
+        self.status
+            .is_some_and(|status| (200..300).contains(&status))
+    }
+}
+
+impl RouterConfig {
+    /// Sends a GET to the `health_check_url` (or `api_base`) of every enabled
+    /// LLM, concurrently and bounded by `PROBE_TIMEOUT` each. This is a
+    /// pre-flight check and performs network I/O, so loading a config never
+    /// calls it.
+    pub async fn probe_backends(&self, client: &reqwest::Client) -> Vec<ProbeResult> {
+        join_all(
+            self.all_llms()
+                .map(|(policy, llm)| probe_backend(client, policy, llm)),
+        )
+        .await
+    }
+}
+
+async fn probe_backend(client: &reqwest::Client, policy: &Policy, llm: &Llm) -> ProbeResult {
+    let url = llm
+        .health_check_url()
+        .unwrap_or_else(|| llm.api_base.clone());
+    let started = Instant::now();
+    let response = client.get(&url).timeout(PROBE_TIMEOUT).send().await;
+    let (status, error) = match response {
+        Ok(response) => (Some(response.status().as_u16()), None),
+        Err(err) => (None, Some(err.to_string())),
+    };
+    ProbeResult {
+        policy: policy.name.clone(),
+        llm: llm.name.clone(),
+        url,
+        status,
+        latency: started.elapsed(),
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{test_config, test_llm, test_policy};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn llm(name: &str, api_base: String, health_check_path: Option<&str>) -> Llm {
+        Llm {
+            api_base,
+            health_check_path: health_check_path.map(str::to_string),
+            ..test_llm(name, None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_probe_backends_reports_each_llm() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/health/ready"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        // Reserve a port, then free it so connecting is refused
+        let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let closed_base = format!("http://{}", closed.local_addr().unwrap());
+        drop(closed);
+
+        let config = test_config(vec![test_policy(
+            "task_router",
+            vec![
+                llm("up", mock_server.uri(), Some("/v1/health/ready")),
+                llm("down", closed_base.clone(), None),
+            ],
+        )]);
+
+        let results = config.probe_backends(&reqwest::Client::new()).await;
+        assert_eq!(results.len(), 2);
+
+        assert_eq!(results[0].llm, "up");
+        assert_eq!(
+            results[0].url,
+            format!("{}/v1/health/ready", mock_server.uri())
+        );
+        assert_eq!(results[0].status, Some(200));
+        assert!(results[0].is_healthy());
+        assert!(results[0].error.is_none());
+
+        assert_eq!(results[1].llm, "down");
+        assert_eq!(results[1].url, closed_base);
+        assert_eq!(results[1].status, None);
+        assert!(!results[1].is_healthy());
+        assert!(results[1].error.is_some());
+    }
+}
@@ -26,7 +26,7 @@ use crate::triton::{InferInputTensor, InferInputs, Output};
 use bytes::Bytes;
 use http::StatusCode;
 use http_body_util::{combinators::BoxBody, BodyExt, Full};
-use hyper::body::Incoming;
+use hyper::body::{Body, Incoming};
 use hyper::{Method, Request, Response, Uri};
 use log::{debug, error, info};
 use prometheus::{gather, Encoder, TextEncoder};
@@ -41,7 +41,7 @@ fn print_config(config: &RouterConfig) {
     debug!("{:#?}", config);
 }
 
-fn extract_forward_uri_path_and_query(req: &Request<Incoming>) -> Result<Uri, GatewayApiError> {
+fn extract_forward_uri_path_and_query<B>(req: &Request<B>) -> Result<Uri, GatewayApiError> {
     let uri = req
         .uri()
         .path_and_query()
@@ -235,36 +235,45 @@ fn remove_nim_llm_router_params(mut value: Value) -> Value {
 //     value
 // }
 
-pub fn config(
-    config: RouterConfig,
+/// Serializes `body` into a response with `status`, logged under `route`.
+fn json_response(
+    route: &str,
+    status: u16,
+    body: &impl serde::Serialize,
 ) -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError> {
-    let config = config.sanitized();
-    let json_vec = serde_json::to_vec(&config).expect("Serialization to JSON should succeed.");
+    let json_vec = serde_json::to_vec(body).expect("Serialization to JSON should succeed.");
     let body_bytes = Bytes::from(json_vec);
 
     let full_body = Full::from(body_bytes)
         .map_err(|never| match never {})
         .boxed();
 
-    let client_res = Response::builder().status(200).body(full_body)?;
+    let client_res = Response::builder().status(status).body(full_body)?;
 
-    info!("/config: {client_res:#?}");
+    info!("{route}: {client_res:#?}");
     Ok(client_res)
 }
 
-pub fn health() -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError> {
-    let body = serde_json::json!({ "status": "OK" });
-    let json_vec = serde_json::to_vec(&body).expect("Serialization to JSON should succeed.");
-    let body_bytes = Bytes::from(json_vec);
+pub fn config(
+    config: RouterConfig,
+) -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError> {
+    json_response("/config", 200, &config.sanitized_full())
+}
 
-    let full_body = Full::from(body_bytes)
-        .map_err(|never| match never {})
-        .boxed();
+pub fn stats(
+    config: RouterConfig,
+) -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError> {
+    json_response("/stats", 200, &config.stats())
+}
 
-    let client_res = Response::builder().status(200).body(full_body)?;
+pub fn models(
+    config: RouterConfig,
+) -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError> {
+    json_response("/v1/models", 200, &config.to_model_list())
+}
 
-    info!("/health: {client_res:#?}");
-    Ok(client_res)
+pub fn health() -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError> {
+    json_response("/health", 200, &serde_json::json!({ "status": "OK" }))
 }
 
 pub fn metrics() -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError> {
@@ -300,18 +309,7 @@ pub fn metrics() -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApi
 }
 
 pub fn unavailable() -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError> {
-    let body = serde_json::json!({ "path": "Unavailable" });
-    let json_vec = serde_json::to_vec(&body).expect("Serialization to JSON should succeed.");
-    let body_bytes = Bytes::from(json_vec);
-
-    let full_body = Full::from(body_bytes)
-        .map_err(|never| match never {})
-        .boxed();
-
-    let client_res = Response::builder().status(404).body(full_body)?;
-
-    info!("/: {client_res:#?}");
-    Ok(client_res)
+    json_response("/", 404, &serde_json::json!({ "path": "Unavailable" }))
 }
 
 pub async fn handler(
@@ -326,6 +324,14 @@ pub async fn handler(
             info!("Routing to config handler");
             config(cfg)
         }
+        "/stats" => {
+            info!("Routing to stats handler");
+            stats(cfg)
+        }
+        "/v1/models" => {
+            info!("Routing to models handler");
+            models(cfg)
+        }
         "/health" => {
             info!("Routing to health handler");
             health()
@@ -345,10 +351,14 @@ pub async fn handler(
     }
 }
 
-pub async fn proxy(
-    req: Request<Incoming>,
+pub async fn proxy<B>(
+    req: Request<B>,
     config: RouterConfig,
-) -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError> {
+) -> Result<Response<BoxBody<Bytes, GatewayApiError>>, GatewayApiError>
+where
+    B: Body<Data = Bytes>,
+    GatewayApiError: From<B::Error>,
+{
     let overall_start = Instant::now();
     let mut model_selection_time = 0.0;
     let llm_resp_time_holder = Arc::new(Mutex::new(0.0));
@@ -393,7 +403,7 @@ pub async fn proxy(
         let client = reqwest::Client::new();
 
         let policy = if let Some(nim_llm_router_params) = extract_nim_llm_router_params(&json) {
-            match config.get_policy_by_name(nim_llm_router_params.policy.as_str()) {
+            match config.get_policy_or_default(nim_llm_router_params.policy.as_str()) {
                 Some(policy) => policy,
                 None => {
                     let error = GatewayApiError::PolicyNotFound(nim_llm_router_params.policy.clone());
@@ -411,6 +421,41 @@ pub async fn proxy(
             .with_label_values(&[policy.name.as_str()])
             .inc();
 
+        if let Some(maintenance) = &policy.maintenance {
+            info!("Policy {} is in maintenance, skipping LLM selection", policy.name);
+            // Validation keeps the status code in range
+            let status = StatusCode::from_u16(maintenance.effective_status_code())
+                .unwrap_or(StatusCode::SERVICE_UNAVAILABLE);
+            let (canned, content_type) = if is_stream {
+                (policy.maintenance_stream(), "text/event-stream")
+            } else {
+                (
+                    policy.maintenance_response().map(|canned| canned.to_string()),
+                    "application/json",
+                )
+            };
+            let body = Full::from(canned.unwrap_or_default().into_bytes())
+                .map_err(|never| match never {})
+                .boxed();
+            return Ok(Response::builder()
+                .status(status)
+                .header(CONTENT_TYPE, content_type)
+                .body(body)?);
+        }
+
+        if !policy.allows_request_size(body_bytes.len()) {
+            let error = GatewayApiError::client_error(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!(
+                    "Request body of {} bytes exceeds the limit of policy '{}'",
+                    body_bytes.len(),
+                    policy.name
+                ),
+                "request_too_large",
+            );
+            return Ok(error.into_response());
+        }
+
         let routing_strategy =
             extract_nim_llm_router_params(&json).and_then(|params| params.routing_strategy);
 
@@ -453,7 +498,7 @@ pub async fn proxy(
                     .and_then(|params| params.threshold)
                     .unwrap_or(0.5);
                 let triton_text = get_last_message_for_triton(&messages);
-                match choose_model(&policy, &client, &triton_text, threshold).await {
+                match choose_model(policy, &client, &triton_text, threshold).await {
                     Ok(index) => {
                         model_selection_time = selection_start.elapsed().as_secs_f64();
                         MODEL_SELECTION_TIME.observe(model_selection_time);
@@ -489,7 +534,7 @@ pub async fn proxy(
             }
         };
 
-        let chosen_llm = policy.get_llm_by_index(model_index).ok_or_else(|| {
+        let chosen_llm = policy.get_llm_by_index_ref(model_index).ok_or_else(|| {
             GatewayApiError::ModelNotFound(format!("LLM not found at index {}", model_index))
         })?;
 
@@ -513,6 +558,24 @@ pub async fn proxy(
         let json = remove_nim_llm_router_params(json);
         info!("json after removing nim llm router params: {json:?}");
 
+        let mut json = policy.apply_request_template(&json);
+        debug!("json after applying request template: {:#?}", &json);
+
+        if let Some(Value::Array(messages)) = json.get_mut("messages") {
+            policy.inject_system_prompt(messages);
+        }
+
+        let requested_max_tokens = json
+            .get("max_tokens")
+            .and_then(Value::as_u64)
+            .map(|tokens| u32::try_from(tokens).unwrap_or(u32::MAX));
+        if let (Some(max_tokens), Some(body)) = (
+            policy.clamp_max_tokens(requested_max_tokens),
+            json.as_object_mut(),
+        ) {
+            body.insert("max_tokens".to_string(), max_tokens.into());
+        }
+
         let json = modify_model(json, model)?;
         debug!("json after modifying model: {:#?}", &json);
 
@@ -527,6 +590,9 @@ pub async fn proxy(
             AUTHORIZATION,
             HeaderValue::from_str(&format!("Bearer {}", api_key))?,
         );
+        // Configured headers were checked at load time and may replace the
+        // defaults, e.g. Azure's api-key in place of Authorization
+        headers.extend(policy.effective_headers_for(chosen_llm));
 
         let uri = format!("{}{}", api_base, forward_uri_path_and_query);
         let mut reqwest_request = client.request(method, uri).json(&json);
@@ -660,7 +726,6 @@ pub async fn proxy(
 mod tests {
     use super::*;
     use crate::config::Llm;
-    use hyper::body::Body;
     use hyper::Request;
     use serde_json::json;
 
@@ -675,15 +740,19 @@ mod tests {
                         api_base: "https://integrate.api.nvidia.com".to_string(),
                         api_key: "test-key".to_string(),
                         model: "meta/llama-3.1-8b-instruct".to_string(),
+                        ..Default::default()
                     },
                     Llm {
                         name: "Code Generation".to_string(),
                         api_base: "https://integrate.api.nvidia.com".to_string(),
                         api_key: "test-key".to_string(),
                         model: "meta/llama-3.1-8b-instruct".to_string(),
+                        ..Default::default()
                     },
                 ],
+                ..Default::default()
             }],
+            ..Default::default()
         }
     }
 
@@ -721,7 +790,31 @@ mod tests {
             .method("POST")
             .uri("/v1/chat/completions")
             .header("content-type", "application/json")
-            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .body(Full::new(Bytes::from(serde_json::to_vec(&body).unwrap())))
+            .expect("Failed to create request");
+
+        let response = proxy(req, config).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_policy_not_found() {
+        let mut config = create_test_config();
+        config.policies[0].enabled = Some(false);
+        let body = json!({
+            "messages": [{"role": "user", "content": "Hello"}],
+            "nim-llm-router": {
+                "policy": "test_policy",
+                "routing_strategy": "manual",
+                "model": "meta/llama-3.1-8b-instruct"
+            }
+        });
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/v1/chat/completions")
+            .header("content-type", "application/json")
+            .body(Full::new(Bytes::from(serde_json::to_vec(&body).unwrap())))
             .expect("Failed to create request");
 
         let response = proxy(req, config).await.unwrap();
@@ -744,7 +837,7 @@ mod tests {
             .method("POST")
             .uri("/v1/chat/completions")
             .header("content-type", "application/json")
-            .body(hyper::Body::from(serde_json::to_vec(&body).unwrap()))
+            .body(Full::new(Bytes::from(serde_json::to_vec(&body).unwrap())))
             .expect("Failed to create request");
 
         let response = proxy(req, config).await.unwrap();
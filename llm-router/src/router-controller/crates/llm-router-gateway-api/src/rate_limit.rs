@@ -0,0 +1,105 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rate limit
+use crate::config::{Policy, RateLimit};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Token bucket built from a policy's `rate_limit`. Clones share the same
+/// bucket, so one limiter can be handed to every request handler.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Starts with a full bucket of `burst` tokens, refilled continuously at
+    /// `requests_per_second`.
+    pub fn new(limit: &RateLimit) -> Self {
+        Self {
+            requests_per_second: f64::from(limit.requests_per_second),
+            burst: f64::from(limit.burst),
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: f64::from(limit.burst),
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// Takes a token if one is available. Returns `false` when the request
+    /// should be rejected.
+    pub fn try_acquire(&self) -> bool {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.requests_per_second).min(self.burst);
+        bucket.refilled_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Policy {
+    /// A fresh limiter for this policy, or `None` if it is not rate limited.
+    pub fn rate_limiter(&self) -> Option<RateLimiter> {
+        self.rate_limit.as_ref().map(RateLimiter::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_rate_limiter_denies_when_drained() {
+        assert_send_sync::<RateLimiter>();
+        let policy = Policy {
+            name: "limited".to_string(),
+            rate_limit: Some(RateLimit {
+                requests_per_second: 1,
+                burst: 3,
+            }),
+            ..Default::default()
+        };
+        let limiter = policy.rate_limiter().unwrap();
+        let shared = limiter.clone();
+
+        assert!(limiter.try_acquire());
+        assert!(shared.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(!shared.try_acquire());
+        assert!(!limiter.try_acquire());
+    }
+
+    #[test]
+    fn test_policy_without_rate_limit() {
+        assert!(Policy::default().rate_limiter().is_none());
+    }
+}
@@ -0,0 +1,447 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Routing
+use crate::config::{Policy, Result, RouterConfig};
+use crate::error::ConfigError;
+use std::collections::{BTreeMap, HashMap};
+use tracing::warn;
+
+/// Requested model names mapped to the index of the policy serving them,
+/// built once by `RouterConfig::build_routing_table` so routing a request is a
+/// single lookup instead of a scan over every policy.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoutingTable {
+    routes: HashMap<String, usize>,
+}
+
+impl RoutingTable {
+    /// Index into `RouterConfig::policies` of the policy serving `model`.
+    /// Names are matched trimmed and ignoring ASCII case, like
+    /// `RouterConfig::find_llm`.
+    pub fn route(&self, model: &str) -> Option<usize> {
+        self.routes.get(&route_key(model)).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+}
+
+fn route_key(model: &str) -> String {
+    model.trim().to_ascii_lowercase()
+}
+
+impl RouterConfig {
+    /// Checks the routing graph as a whole, beyond what the per-field
+    /// validators see. Its edges are model aliases, from the model an alias
+    /// resolves to into the LLMs serving it and, within each enabled policy,
+    /// failover steps: consecutive `fallback_order` entries and moves from one
+    /// `tier` to the next. Fails with `ConfigError::RoutingGraphCycle`
+    /// on a loop, such as a `fallback_order` that puts a higher tier first, and
+    /// with `ConfigError::UnreachableLlm` on an enabled LLM that neither
+    /// weighted selection nor `fallback_order` can ever reach. Repeated
+    /// `fallback_order` entries count once, as in `Policy::fallback_iter`.
+    /// Only policies with a `fallback_order` can have unreachable LLMs: the
+    /// default failover order, that of `llms`, reaches every enabled LLM.
+    ///
+    /// LLMs are named `policy/llm` in the reported path.
+    pub fn validate_routing_graph(&self) -> Result<()> {
+        if let Some(path) = find_cycle(&self.routing_graph()) {
+            return Err(ConfigError::RoutingGraphCycle { path });
+        }
+        for policy in self.policies.iter().filter(|policy| policy.is_enabled()) {
+            let Some(order) = &policy.fallback_order else {
+                continue;
+            };
+            let unreachable = policy.enabled_llms().into_iter().find(|llm| {
+                llm.effective_weight() == 0
+                    && !order.iter().any(|name| name.trim() == llm.name.trim())
+            });
+            if let Some(llm) = unreachable {
+                return Err(ConfigError::UnreachableLlm {
+                    policy: policy.name.clone(),
+                    llm: llm.name.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Precomputes model routing: every model served by an enabled LLM of an
+    /// enabled policy, plus every alias resolving to one of them. A model
+    /// served by several policies routes to the first of them, with a warning.
+    /// Policies reached only through `model_pattern` are not in the table, as
+    /// patterns cannot be enumerated.
+    pub fn build_routing_table(&self) -> RoutingTable {
+        let mut routes = HashMap::new();
+        let enabled = self
+            .policies
+            .iter()
+            .enumerate()
+            .filter(|(_, policy)| policy.is_enabled());
+        for (index, policy) in enabled {
+            for llm in policy.enabled_llms() {
+                let key = route_key(&llm.model);
+                match routes.get(&key) {
+                    None => {
+                        routes.insert(key, index);
+                    }
+                    Some(&first) if first != index => warn!(
+                        "Model '{}' is served by policies '{}' and '{}', routing it to '{}'",
+                        llm.model.trim(),
+                        self.policies[first].name,
+                        policy.name,
+                        self.policies[first].name
+                    ),
+                    Some(_) => {}
+                }
+            }
+        }
+
+        let mut aliases: Vec<&String> = self
+            .aliases
+            .iter()
+            .flatten()
+            .map(|(alias, _)| alias)
+            .collect();
+        aliases.sort();
+        let alias_routes: Vec<_> = aliases
+            .into_iter()
+            .filter_map(|alias| {
+                let index = routes.get(&route_key(self.resolve_model_alias(alias)))?;
+                Some((route_key(alias), *index))
+            })
+            .collect();
+        // Aliases win over a model of the same name, as in `find_llm`
+        routes.extend(alias_routes);
+        RoutingTable { routes }
+    }
+
+    fn routing_graph(&self) -> BTreeMap<String, Vec<String>> {
+        let mut graph: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let aliases = self.aliases.iter().flatten();
+        for (alias, target) in aliases.clone() {
+            graph.entry(alias.clone()).or_default().push(target.clone());
+        }
+        // Aliased models lead into the LLMs serving them, or the policy whose
+        // `model_pattern` matches when no LLM names the model
+        for (_, target) in aliases {
+            if graph.contains_key(target) {
+                continue;
+            }
+            let enabled = || self.policies.iter().filter(|policy| policy.is_enabled());
+            let mut serving: Vec<String> = enabled()
+                .flat_map(|policy| {
+                    policy
+                        .enabled_llms()
+                        .into_iter()
+                        .filter(|llm| route_key(&llm.model) == route_key(target))
+                        .map(move |llm| node(policy, &llm.name))
+                })
+                .collect();
+            if serving.is_empty() {
+                if let Some(policy) = self.policy_for_model(target) {
+                    serving = policy
+                        .enabled_llms()
+                        .into_iter()
+                        .map(|llm| node(policy, &llm.name))
+                        .collect();
+                }
+            }
+            graph.insert(target.clone(), serving);
+        }
+        for policy in self.policies.iter().filter(|policy| policy.is_enabled()) {
+            for (from, to) in failover_edges(policy) {
+                graph
+                    .entry(node(policy, from))
+                    .or_default()
+                    .push(node(policy, to));
+            }
+        }
+        graph
+    }
+}
+
+fn node(policy: &Policy, llm: &str) -> String {
+    format!("{}/{}", policy.name.trim(), llm.trim())
+}
+
+/// Failover steps between the enabled LLMs of `policy`, by LLM name.
+fn failover_edges(policy: &Policy) -> Vec<(&str, &str)> {
+    let enabled = policy.enabled_llms();
+    let mut edges = Vec::new();
+
+    // Repeated entries are skipped, as `Policy::fallback_iter` does
+    let mut ordered: Vec<&str> = Vec::new();
+    let names = policy.fallback_order.iter().flatten();
+    for llm in names.filter_map(|name| enabled.iter().find(|llm| llm.name.trim() == name.trim())) {
+        if !ordered.contains(&llm.name.as_str()) {
+            ordered.push(llm.name.as_str());
+        }
+    }
+    edges.extend(ordered.windows(2).map(|pair| (pair[0], pair[1])));
+
+    if enabled.iter().any(|llm| llm.tier.is_some()) {
+        // Tiered selection only draws from LLMs with a non-zero weight
+        let mut tiers: BTreeMap<u32, Vec<&str>> = BTreeMap::new();
+        for llm in enabled.iter().filter(|llm| llm.effective_weight() > 0) {
+            tiers
+                .entry(llm.effective_tier())
+                .or_default()
+                .push(llm.name.as_str());
+        }
+        let tiers: Vec<_> = tiers.into_values().collect();
+        for pair in tiers.windows(2) {
+            for from in &pair[0] {
+                edges.extend(pair[1].iter().map(|to| (*from, *to)));
+            }
+        }
+    }
+    edges
+}
+
+/// The first cycle found, as the path from its first node back to that node.
+fn find_cycle(graph: &BTreeMap<String, Vec<String>>) -> Option<Vec<String>> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum State {
+        Visiting,
+        Done,
+    }
+
+    fn visit<'a>(
+        node: &'a str,
+        graph: &'a BTreeMap<String, Vec<String>>,
+        states: &mut HashMap<&'a str, State>,
+        stack: &mut Vec<&'a str>,
+    ) -> Option<Vec<String>> {
+        states.insert(node, State::Visiting);
+        stack.push(node);
+        for next in graph.get(node).into_iter().flatten() {
+            match states.get(next.as_str()) {
+                Some(State::Visiting) => {
+                    let start = stack.iter().position(|entry| *entry == next)?;
+                    let mut path: Vec<String> = stack[start..]
+                        .iter()
+                        .map(|entry| entry.to_string())
+                        .collect();
+                    path.push(next.clone());
+                    return Some(path);
+                }
+                Some(State::Done) => {}
+                None => {
+                    if let Some(path) = visit(next, graph, states, stack) {
+                        return Some(path);
+                    }
+                }
+            }
+        }
+        stack.pop();
+        states.insert(node, State::Done);
+        None
+    }
+
+    let mut states = HashMap::new();
+    for node in graph.keys() {
+        if !states.contains_key(node.as_str()) {
+            if let Some(path) = visit(node, graph, &mut states, &mut Vec::new()) {
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Llm;
+    use crate::test_fixtures::{test_config, test_llm, test_policy};
+
+    fn llm(name: &str, tier: Option<u32>, weight: Option<u32>) -> Llm {
+        Llm {
+            tier,
+            ..test_llm(name, weight)
+        }
+    }
+
+    fn policy_config(llms: Vec<Llm>, fallback_order: &[&str]) -> RouterConfig {
+        test_config(vec![Policy {
+            fallback_order: Some(fallback_order.iter().map(|name| name.to_string()).collect()),
+            ..test_policy("task_router", llms)
+        }])
+    }
+
+    #[test]
+    fn test_routing_graph_ignores_repeated_fallback_entries() {
+        let config = policy_config(
+            vec![llm("a", None, None), llm("b", None, None)],
+            &["a", "b", "a"],
+        );
+        assert!(config.validate_routing_graph().is_ok());
+        assert_eq!(
+            config.routing_graph()["task_router/a"],
+            vec!["task_router/b"]
+        );
+        let order: Vec<&str> = config.policies[0]
+            .fallback_iter()
+            .map(|llm| llm.name.as_str())
+            .collect();
+        assert_eq!(order, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_routing_graph_detects_fallback_against_tiers() {
+        let config = policy_config(
+            vec![
+                llm("primary", Some(0), None),
+                llm("secondary", Some(1), None),
+            ],
+            &["secondary", "primary"],
+        );
+        match config.validate_routing_graph() {
+            Err(ConfigError::RoutingGraphCycle { path }) => assert_eq!(
+                path,
+                vec![
+                    "task_router/primary",
+                    "task_router/secondary",
+                    "task_router/primary"
+                ]
+            ),
+            other => panic!("expected RoutingGraphCycle, got {:?}", other),
+        }
+
+        let config = policy_config(
+            vec![
+                llm("primary", Some(0), None),
+                llm("secondary", Some(1), None),
+            ],
+            &["primary", "secondary"],
+        );
+        assert!(config.validate_routing_graph().is_ok());
+    }
+
+    #[test]
+    fn test_routing_graph_detects_unreachable_llm() {
+        let config = policy_config(
+            vec![llm("a", None, None), llm("idle", None, Some(0))],
+            &["a"],
+        );
+        assert!(matches!(
+            config.validate_routing_graph(),
+            Err(ConfigError::UnreachableLlm { policy, llm }) if policy == "task_router" && llm == "idle"
+        ));
+
+        // Without a fallback_order, failover walks every enabled LLM
+        let mut config = config;
+        config.policies[0].fallback_order = None;
+        assert!(config.validate_routing_graph().is_ok());
+        assert!(config.policies[0]
+            .fallback_iter()
+            .any(|llm| llm.name == "idle"));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_routing_table_routes_models_and_aliases() {
+        let serve = |name: &str, model: &str| Llm {
+            model: model.to_string(),
+            ..llm(name, None, None)
+        };
+        let mut config = policy_config(vec![serve("a", "meta/llama-3.1-8b-instruct")], &[]);
+        let mut second = config.policies[0].clone();
+        second.name = "complexity_router".to_string();
+        second.llms = vec![
+            serve("b", "Meta/Llama-3.1-8B-Instruct"),
+            serve("c", "mistralai/mixtral-8x22b-instruct-v0.1"),
+        ];
+        let mut disabled = second.clone();
+        disabled.name = "disabled".to_string();
+        disabled.enabled = Some(false);
+        disabled.llms = vec![serve("d", "nvidia/nemotron")];
+        config.policies.extend([second, disabled]);
+        config.aliases = Some(HashMap::from([
+            (
+                "mixtral".to_string(),
+                "mistralai/mixtral-8x22b-instruct-v0.1".to_string(),
+            ),
+            ("ghost".to_string(), "nvidia/nemotron".to_string()),
+        ]));
+
+        let table = config.build_routing_table();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.route("meta/llama-3.1-8b-instruct"), Some(0));
+        assert_eq!(
+            table.route(" MISTRALAI/mixtral-8x22b-instruct-v0.1"),
+            Some(1)
+        );
+        assert_eq!(table.route("mixtral"), Some(1));
+        assert_eq!(table.route("nvidia/nemotron"), None);
+        assert_eq!(table.route("ghost"), None);
+        assert!(logs_contain(
+            "served by policies 'task_router' and 'complexity_router', routing it to 'task_router'"
+        ));
+    }
+
+    #[test]
+    fn test_routing_graph_follows_aliases_into_failover() {
+        let serve = |name: &str, model: &str, tier: u32| Llm {
+            model: model.to_string(),
+            ..llm(name, Some(tier), None)
+        };
+        let mut config = policy_config(
+            vec![
+                serve("primary", "gpt-4o", 0),
+                serve("secondary", "gpt-4o-mini", 1),
+            ],
+            &["secondary", "primary"],
+        );
+        config.aliases = Some(HashMap::from([("fast".to_string(), "GPT-4o".to_string())]));
+
+        let graph = config.routing_graph();
+        assert_eq!(graph["fast"], vec!["GPT-4o"]);
+        assert_eq!(graph["GPT-4o"], vec!["task_router/primary"]);
+        // The loop is found by following the alias into the policy
+        match config.validate_routing_graph() {
+            Err(ConfigError::RoutingGraphCycle { path }) => assert_eq!(
+                path,
+                vec![
+                    "task_router/primary",
+                    "task_router/secondary",
+                    "task_router/primary"
+                ]
+            ),
+            other => panic!("expected RoutingGraphCycle, got {:?}", other),
+        }
+
+        // Models no LLM names are served by the policy matching them
+        config.policies[0].model_pattern = Some("^claude-".to_string());
+        config.policies[0].fallback_order = None;
+        config.aliases = Some(HashMap::from([(
+            "smart".to_string(),
+            "claude-3-5-sonnet".to_string(),
+        )]));
+        let graph = config.routing_graph();
+        assert_eq!(
+            graph["claude-3-5-sonnet"],
+            vec!["task_router/primary", "task_router/secondary"]
+        );
+        assert!(config.validate_routing_graph().is_ok());
+    }
+}
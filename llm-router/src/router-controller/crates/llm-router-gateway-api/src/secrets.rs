@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secrets
+use crate::config::Result;
+use crate::error::ConfigError;
+use base64::Engine;
+
+/// Prefix marking an `api_key` as a reference to be looked up by a
+/// `SecretResolver`, e.g. `secret://openai-prod`.
+pub const SECRET_PREFIX: &str = "secret://";
+
+/// Prefix marking an `api_key` as base64 encoded, e.g. `base64:bnZhcGktMTIz`.
+/// This only keeps keys from being readable at a glance; anyone with the
+/// config can decode them.
+pub const BASE64_PREFIX: &str = "base64:";
+
+/// Decodes the part of `llm`'s API key after `base64:`. Trailing whitespace,
+/// such as the newline `echo key | base64` encodes, is dropped.
+pub(crate) fn decode_base64_key(llm: &str, encoded: &str) -> Result<String> {
+    let invalid = |message: String| ConfigError::InvalidSecretEncoding {
+        llm: llm.to_string(),
+        message,
+    };
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded.trim())
+        .map_err(|err| invalid(err.to_string()))?;
+    let key = String::from_utf8(bytes).map_err(|err| invalid(err.to_string()))?;
+    Ok(key.trim_end().to_string())
+}
+
+/// Looks up secrets referenced from the config, such as keys stored in Vault or
+/// AWS Secrets Manager. Resolution runs after `${VAR}` expansion.
+pub trait SecretResolver {
+    /// Returns the secret stored under `key`, the part after `secret://`.
+    fn resolve(&self, key: &str) -> Result<String>;
+}
+
+/// Resolves `secret://NAME` from the environment variable `NAME`. Used when no
+/// other resolver is given.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnvSecretResolver;
+
+impl SecretResolver for EnvSecretResolver {
+    fn resolve(&self, key: &str) -> Result<String> {
+        std::env::var(key).map_err(|_| ConfigError::UnresolvedSecret {
+            key: key.to_string(),
+            message: format!("environment variable '{}' is not set", key),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_base64_key() {
+        assert_eq!(
+            decode_base64_key("a", "bnZhcGktc2VjcmV0Cg==").unwrap(),
+            "nvapi-secret"
+        );
+        assert!(matches!(
+            decode_base64_key("a", "not base64!"),
+            Err(ConfigError::InvalidSecretEncoding { llm, .. }) if llm == "a"
+        ));
+    }
+
+    #[test]
+    fn test_env_secret_resolver() {
+        std::env::set_var("SECRETS_TEST_PRESENT", "from-env");
+        assert_eq!(
+            EnvSecretResolver.resolve("SECRETS_TEST_PRESENT").unwrap(),
+            "from-env"
+        );
+        assert!(matches!(
+            EnvSecretResolver.resolve("SECRETS_TEST_MISSING"),
+            Err(ConfigError::UnresolvedSecret { key, .. }) if key == "SECRETS_TEST_MISSING"
+        ));
+    }
+}
@@ -0,0 +1,452 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Selector
+use crate::config::{cheapest, Llm, Policy, Result};
+use crate::error::ConfigError;
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Round-robin position over a policy's LLMs. Share one cursor per policy
+/// across request handlers, e.g. behind an `Arc`.
+#[derive(Debug, Default)]
+pub struct PolicyCursor {
+    position: AtomicUsize,
+}
+
+impl PolicyCursor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the next enabled LLM in declaration order, wrapping around at the
+    /// end. Returns `None` for a policy without enabled LLMs.
+    pub fn next_llm<'a>(&self, policy: &'a Policy) -> Option<&'a Llm> {
+        self.next_of(policy.enabled_llms())
+    }
+
+    fn next_of<'a>(&self, llms: Vec<&'a Llm>) -> Option<&'a Llm> {
+        if llms.is_empty() {
+            return None;
+        }
+        let position = self.position.fetch_add(1, Ordering::Relaxed);
+        Some(llms[position % llms.len()])
+    }
+}
+
+/// How `Policy::select` chooses among a policy's LLMs.
+#[derive(Debug, Clone)]
+pub enum SelectStrategy {
+    /// The first LLM in failover order, see `Policy::fallback_iter`.
+    FirstAvailable,
+    /// See `Policy::pick_weighted_llm`.
+    Weighted,
+    /// See `PolicyCursor::next_llm`. The cursor should be shared by every
+    /// request on the policy.
+    RoundRobin(Arc<PolicyCursor>),
+    /// See `Policy::tiered_pick`.
+    Tiered,
+    /// See `Policy::cheapest_llm`.
+    Cheapest,
+    /// See `Policy::pick_llm_for_key`.
+    StickyKey(String),
+}
+
+impl Policy {
+    /// Picks an enabled LLM with `strategy`. LLMs with a zero weight are never
+    /// picked, whatever the strategy. Only `Weighted` and `Tiered` draw from
+    /// `rng`.
+    pub fn select(&self, strategy: &SelectStrategy, rng: &mut impl Rng) -> Option<&Llm> {
+        let weighted = |llm: &&Llm| llm.effective_weight() > 0;
+        match strategy {
+            SelectStrategy::FirstAvailable => self.fallback_iter().find(weighted),
+            SelectStrategy::Weighted => self.pick_weighted_llm(rng),
+            SelectStrategy::RoundRobin(cursor) => {
+                cursor.next_of(self.enabled_llms().into_iter().filter(weighted).collect())
+            }
+            SelectStrategy::Tiered => self.tiered_pick(rng),
+            SelectStrategy::Cheapest => cheapest(self.enabled_llms().into_iter().filter(weighted)),
+            SelectStrategy::StickyKey(key) => self.pick_llm_for_key(key),
+        }
+    }
+}
+
+/// Weighted selection over a policy that adapts to backend health: each LLM's
+/// configured weight is scaled by its recent success rate. Outcomes are
+/// recorded lock-free, so one selector can be shared across request handlers.
+#[derive(Debug)]
+pub struct AdaptiveSelector {
+    policy: Policy,
+    decay: f64,
+    outcomes: HashMap<String, Outcomes>,
+}
+
+/// Decayed success and failure counts, stored as `f64` bits.
+#[derive(Debug, Default)]
+struct Outcomes {
+    successes: AtomicU64,
+    failures: AtomicU64,
+}
+
+impl Outcomes {
+    fn record(&self, decay: f64, success: bool) {
+        let update = |counter: &AtomicU64, increment: f64| {
+            // The closure always returns Some, so this cannot fail
+            let _ = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                Some((f64::from_bits(bits) * decay + increment).to_bits())
+            });
+        };
+        update(&self.successes, if success { 1.0 } else { 0.0 });
+        update(&self.failures, if success { 0.0 } else { 1.0 });
+    }
+
+    /// Smoothed success rate, 0.5 before any outcome is recorded.
+    fn success_rate(&self) -> f64 {
+        let successes = f64::from_bits(self.successes.load(Ordering::Relaxed));
+        let failures = f64::from_bits(self.failures.load(Ordering::Relaxed));
+        (successes + 1.0) / (successes + failures + 2.0)
+    }
+}
+
+impl AdaptiveSelector {
+    /// `decay`, between 0 and 1, scales down past outcomes each time a new one
+    /// is recorded for the same LLM: lower values forget faster, and 1 never
+    /// forgets. Fails with `ConfigError::InvalidDecay` if `decay` is NaN or
+    /// outside `0.0..=1.0`, which would let counts grow without bound, go
+    /// negative or poison the success rate.
+    pub fn new(policy: Policy, decay: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&decay) {
+            return Err(ConfigError::InvalidDecay {
+                policy: policy.name.clone(),
+                decay,
+            });
+        }
+        let outcomes = policy
+            .llms
+            .iter()
+            .map(|llm| (llm.name.trim().to_string(), Outcomes::default()))
+            .collect();
+        Ok(Self {
+            policy,
+            decay,
+            outcomes,
+        })
+    }
+
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    /// Records a successful request to `llm_name`. Unknown names are ignored.
+    pub fn record_success(&self, llm_name: &str) {
+        if let Some(outcomes) = self.outcomes.get(llm_name.trim()) {
+            outcomes.record(self.decay, true);
+        }
+    }
+
+    /// Records a failed request to `llm_name`. Unknown names are ignored.
+    pub fn record_failure(&self, llm_name: &str) {
+        if let Some(outcomes) = self.outcomes.get(llm_name.trim()) {
+            outcomes.record(self.decay, false);
+        }
+    }
+
+    /// Like `Policy::pick_weighted_llm`, with each weight scaled by the LLM's
+    /// success rate. A failing LLM keeps a small share, so it is still probed
+    /// and can recover.
+    pub fn pick(&self, rng: &mut impl Rng) -> Option<&Llm> {
+        let weighted: Vec<(&Llm, f64)> = self
+            .policy
+            .enabled_llms()
+            .into_iter()
+            .map(|llm| {
+                let rate = self
+                    .outcomes
+                    .get(llm.name.trim())
+                    .map_or(0.5, Outcomes::success_rate);
+                (llm, f64::from(llm.effective_weight()) * rate)
+            })
+            .filter(|(_, weight)| *weight > 0.0)
+            .collect();
+        if weighted.is_empty() {
+            return None;
+        }
+        let total: f64 = weighted.iter().map(|(_, weight)| weight).sum();
+
+        let mut target = rng.gen_range(0.0..total);
+        for (llm, weight) in &weighted {
+            if target < *weight {
+                return Some(llm);
+            }
+            target -= weight;
+        }
+        // Rounding may leave target just past the last weight
+        weighted.last().map(|(llm, _)| *llm)
+    }
+}
+
+/// Routes to the enabled LLM with the lowest recent latency, as reported by a
+/// metrics source keyed by LLM name. The selector holds no latency data itself,
+/// so the source decides what "recent" means.
+pub struct LatencyAwareSelector<F> {
+    policy: Policy,
+    latency: F,
+}
+
+impl<F> LatencyAwareSelector<F>
+where
+    F: Fn(&str) -> Option<Duration>,
+{
+    pub fn new(policy: Policy, latency: F) -> Self {
+        Self { policy, latency }
+    }
+
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    /// The fastest enabled LLM with a non-zero weight, preferring the higher
+    /// weight and then declaration order on equal latency. LLMs without latency
+    /// data are skipped, and when none has any this falls back to
+    /// `Policy::pick_weighted_llm`.
+    pub fn pick(&self, rng: &mut impl Rng) -> Option<&Llm> {
+        let fastest = self
+            .policy
+            .enabled_llms()
+            .into_iter()
+            .filter(|llm| llm.effective_weight() > 0)
+            .filter_map(|llm| (self.latency)(llm.name.trim()).map(|latency| (llm, latency)))
+            .min_by(|(a, a_latency), (b, b_latency)| {
+                a_latency
+                    .cmp(b_latency)
+                    .then_with(|| b.effective_weight().cmp(&a.effective_weight()))
+            });
+        match fastest {
+            Some((llm, _)) => Some(llm),
+            None => self.policy.pick_weighted_llm(rng),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{test_llm, test_policy};
+    use rand::SeedableRng;
+    use std::thread;
+
+    fn policy(names: &[&str]) -> Policy {
+        let llms = names.iter().map(|name| test_llm(name, None)).collect();
+        test_policy("round_robin", llms)
+    }
+
+    #[test]
+    fn test_next_llm_wraps_around() {
+        let policy = policy(&["a", "b", "c"]);
+        let cursor = PolicyCursor::new();
+        let names: Vec<&str> = (0..5)
+            .map(|_| cursor.next_llm(&policy).unwrap().name.as_str())
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_next_llm_skips_disabled() {
+        let mut policy = policy(&["a", "b", "c"]);
+        policy.llms[1].enabled = Some(false);
+        let cursor = PolicyCursor::new();
+        let names: Vec<&str> = (0..3)
+            .map(|_| cursor.next_llm(&policy).unwrap().name.as_str())
+            .collect();
+        assert_eq!(names, vec!["a", "c", "a"]);
+    }
+
+    #[test]
+    fn test_next_llm_empty_policy() {
+        let cursor = PolicyCursor::new();
+        assert!(cursor.next_llm(&policy(&[])).is_none());
+    }
+
+    #[test]
+    fn test_next_llm_concurrent_distribution() {
+        let policy = Arc::new(policy(&["a", "b", "c", "d"]));
+        let cursor = Arc::new(PolicyCursor::new());
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let policy = Arc::clone(&policy);
+                let cursor = Arc::clone(&cursor);
+                thread::spawn(move || {
+                    let mut counts = HashMap::new();
+                    for _ in 0..1_000 {
+                        let name = cursor.next_llm(&policy).unwrap().name.clone();
+                        *counts.entry(name).or_insert(0) += 1;
+                    }
+                    counts
+                })
+            })
+            .collect();
+
+        let mut totals: HashMap<String, usize> = HashMap::new();
+        for handle in handles {
+            for (name, count) in handle.join().unwrap() {
+                *totals.entry(name).or_insert(0) += count;
+            }
+        }
+        assert_eq!(totals.len(), 4);
+        assert!(totals.values().all(|&count| count == 2_000), "{:?}", totals);
+    }
+
+    #[test]
+    fn test_select_dispatches_to_each_strategy() {
+        let mut policy = policy(&["off", "primary", "cheap", "backup"]);
+        policy.llms[0].enabled = Some(false);
+        policy.llms[1].input_cost_per_1k = Some(0.5);
+        policy.llms[1].output_cost_per_1k = Some(1.5);
+        policy.llms[2].input_cost_per_1k = Some(0.1);
+        policy.llms[2].output_cost_per_1k = Some(0.2);
+        policy.llms[2].weight = Some(0);
+        policy.llms[3].tier = Some(1);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let mut select = |strategy: &SelectStrategy| {
+            policy
+                .select(strategy, &mut rng)
+                .map(|llm| llm.name.clone())
+        };
+
+        assert_eq!(
+            select(&SelectStrategy::FirstAvailable).as_deref(),
+            Some("primary")
+        );
+        assert_eq!(select(&SelectStrategy::Tiered).as_deref(), Some("primary"));
+        // "cheap" has a zero weight
+        assert_eq!(
+            select(&SelectStrategy::Cheapest).as_deref(),
+            Some("primary")
+        );
+        for _ in 0..100 {
+            let name = select(&SelectStrategy::Weighted).unwrap();
+            assert!(name == "primary" || name == "backup", "{}", name);
+        }
+
+        let cursor = SelectStrategy::RoundRobin(Arc::new(PolicyCursor::new()));
+        let names: Vec<_> = (0..4).map(|_| select(&cursor).unwrap()).collect();
+        assert_eq!(names, vec!["primary", "backup", "primary", "backup"]);
+
+        let sticky = SelectStrategy::StickyKey("session-42".to_string());
+        let first = select(&sticky);
+        assert!(first.is_some());
+        assert!((0..10).all(|_| select(&sticky) == first));
+
+        let mut unweighted = policy.clone();
+        for llm in &mut unweighted.llms {
+            llm.weight = Some(0);
+        }
+        let strategies = [
+            SelectStrategy::FirstAvailable,
+            SelectStrategy::Weighted,
+            cursor,
+            SelectStrategy::Tiered,
+            SelectStrategy::Cheapest,
+            sticky,
+        ];
+        for strategy in &strategies {
+            assert!(
+                unweighted.select(strategy, &mut rng).is_none(),
+                "{:?}",
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn test_adaptive_selector_avoids_failing_llm() {
+        let selector = AdaptiveSelector::new(policy(&["healthy", "flaky"]), 0.9).unwrap();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let flaky_share = |rng: &mut rand::rngs::StdRng| {
+            let picks = (0..10_000)
+                .filter(|_| selector.pick(rng).unwrap().name == "flaky")
+                .count();
+            picks as f64 / 10_000.0
+        };
+
+        let before = flaky_share(&mut rng);
+        assert!((0.45..0.55).contains(&before), "{}", before);
+
+        for _ in 0..50 {
+            selector.record_success("healthy");
+            selector.record_failure("flaky");
+        }
+        let after = flaky_share(&mut rng);
+        assert!(after < 0.15, "{}", after);
+        assert!(after > 0.0);
+
+        // Decay lets a recovered LLM win its share back
+        for _ in 0..50 {
+            selector.record_success("flaky");
+        }
+        assert!(flaky_share(&mut rng) > 0.4);
+    }
+
+    #[test]
+    fn test_adaptive_selector_rejects_invalid_decay() {
+        for decay in [f64::NAN, -0.1, 1.5] {
+            assert!(
+                AdaptiveSelector::new(policy(&["a"]), decay).is_err(),
+                "{}",
+                decay
+            );
+        }
+        assert!(AdaptiveSelector::new(policy(&["a"]), 0.0).is_ok());
+        assert!(AdaptiveSelector::new(policy(&["a"]), 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_latency_aware_selector_prefers_fastest() {
+        let mut policy = policy(&["slow", "fast", "unmeasured", "off"]);
+        policy.llms[3].enabled = Some(false);
+        let latencies: HashMap<&str, Duration> = [
+            ("slow", Duration::from_millis(900)),
+            ("fast", Duration::from_millis(120)),
+            ("off", Duration::from_millis(10)),
+        ]
+        .into();
+        let selector = LatencyAwareSelector::new(policy, |name: &str| latencies.get(name).copied());
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        assert!((0..20).all(|_| selector.pick(&mut rng).unwrap().name == "fast"));
+    }
+
+    #[test]
+    fn test_latency_aware_selector_breaks_ties_by_weight() {
+        let mut policy = policy(&["light", "heavy"]);
+        policy.llms[0].weight = Some(1);
+        policy.llms[1].weight = Some(5);
+        let selector = LatencyAwareSelector::new(policy, |_: &str| Some(Duration::from_millis(50)));
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        assert_eq!(selector.pick(&mut rng).unwrap().name, "heavy");
+    }
+
+    #[test]
+    fn test_latency_aware_selector_falls_back_to_weighted() {
+        let mut policy = policy(&["a", "b"]);
+        policy.llms[0].weight = Some(0);
+        let selector = LatencyAwareSelector::new(policy, |_: &str| None);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        assert!((0..20).all(|_| selector.pick(&mut rng).unwrap().name == "b"));
+    }
+}
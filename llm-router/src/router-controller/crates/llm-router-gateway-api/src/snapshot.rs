@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Snapshot
+use crate::config::RouterConfig;
+use std::sync::Arc;
+
+/// Frozen copy of a config from `RouterConfig::snapshot`, e.g. to reset a
+/// test harness between scenarios without reading the file again. Clones
+/// share the copy, so one snapshot can restore several configs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigSnapshot {
+    config: Arc<RouterConfig>,
+}
+
+impl ConfigSnapshot {
+    /// The config as it was when the snapshot was taken.
+    pub fn config(&self) -> &RouterConfig {
+        &self.config
+    }
+}
+
+impl RouterConfig {
+    /// Copies the config so it can be put back with `restore`. Later changes
+    /// to `self` do not affect the snapshot.
+    pub fn snapshot(&self) -> ConfigSnapshot {
+        ConfigSnapshot {
+            config: Arc::new(self.clone()),
+        }
+    }
+
+    /// Replaces `self` with the config captured by `snapshot`. Only copies
+    /// when other clones of the snapshot are still alive.
+    pub fn restore(&mut self, snapshot: ConfigSnapshot) {
+        *self = Arc::try_unwrap(snapshot.config).unwrap_or_else(|shared| (*shared).clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ConfigFormat, Llm};
+
+    const CONFIG: &str = r#"
+policies:
+  - name: task_router
+    url: http://router-server:8000/v2/models/task_router_ensemble/infer
+    llms:
+      - name: Brainstorming
+        api_base: https://integrate.api.nvidia.com
+        api_key: test-key
+        model: meta/llama-3.1-70b-instruct
+"#;
+
+    #[test]
+    fn test_restore_undoes_mutations() {
+        let mut config = RouterConfig::load_config_from_str(CONFIG, ConfigFormat::Yaml).unwrap();
+        let original = config.clone();
+        let snapshot = config.snapshot();
+
+        config.policies[0].llms[0].model = "mistralai/mixtral-8x22b-instruct-v0.1".to_string();
+        config.policies[0].llms.push(Llm {
+            name: "Chatbot".to_string(),
+            ..Default::default()
+        });
+        config.default_policy = Some("task_router".to_string());
+        assert_eq!(snapshot.config(), &original);
+
+        let reused = snapshot.clone();
+        config.restore(snapshot);
+        assert_eq!(config, original);
+
+        config.policies.clear();
+        config.restore(reused);
+        assert_eq!(config, original);
+    }
+}
@@ -0,0 +1,218 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Source
+use crate::config::{decode_config, read_config_file, ConfigFormat, Result};
+use crate::error::ConfigError;
+use std::cell::RefCell;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Where a config's text comes from, so embedders without a filesystem can
+/// load from memory or their own storage with `RouterConfig::load_from_source`.
+pub trait ConfigSource {
+    /// The whole config text.
+    fn read(&self) -> Result<String>;
+
+    /// How to parse the text returned by `read`.
+    fn format(&self) -> ConfigFormat {
+        ConfigFormat::Yaml
+    }
+}
+
+/// A config file, formatted by its extension like `load_config`.
+#[derive(Debug, Clone)]
+pub struct FileSource {
+    path: PathBuf,
+}
+
+impl FileSource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl ConfigSource for FileSource {
+    fn read(&self) -> Result<String> {
+        read_config_file(&self.path)
+    }
+
+    fn format(&self) -> ConfigFormat {
+        ConfigFormat::from_path(&self.path)
+    }
+}
+
+/// Config text already in memory.
+#[derive(Debug, Clone)]
+pub struct StrSource {
+    content: String,
+    format: ConfigFormat,
+}
+
+impl StrSource {
+    pub fn new(content: impl Into<String>, format: ConfigFormat) -> Self {
+        Self {
+            content: content.into(),
+            format,
+        }
+    }
+}
+
+impl ConfigSource for StrSource {
+    fn read(&self) -> Result<String> {
+        Ok(self.content.clone())
+    }
+
+    fn format(&self) -> ConfigFormat {
+        self.format
+    }
+}
+
+/// A config fetched with a blocking HTTP(S) GET, formatted by the URL path's
+/// extension. Must not be read from an async context; use
+/// `RouterConfig::load_from_url` there, which also honors `Content-Type`.
+#[derive(Debug, Clone)]
+pub struct UrlSource {
+    url: String,
+}
+
+impl UrlSource {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+}
+
+impl ConfigSource for UrlSource {
+    fn read(&self) -> Result<String> {
+        let response = reqwest::blocking::get(&self.url)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ConfigError::RemoteFetchFailed {
+                url: self.url.clone(),
+                status: status.as_u16(),
+            });
+        }
+        decode_config(response.bytes()?.to_vec())
+    }
+
+    fn format(&self) -> ConfigFormat {
+        let path = url::Url::parse(&self.url)
+            .map(|url| url.path().to_string())
+            .unwrap_or_default();
+        ConfigFormat::from_path(path)
+    }
+}
+
+/// A config read to the end from `reader`, e.g. stdin. A reader can only be
+/// drained once, so reading again yields what is left, usually nothing.
+#[derive(Debug)]
+pub struct ReaderSource<R> {
+    reader: RefCell<R>,
+    format: ConfigFormat,
+}
+
+impl<R: Read> ReaderSource<R> {
+    pub fn new(reader: R, format: ConfigFormat) -> Self {
+        Self {
+            reader: RefCell::new(reader),
+            format,
+        }
+    }
+}
+
+impl<R: Read> ConfigSource for ReaderSource<R> {
+    fn read(&self) -> Result<String> {
+        let mut bytes = Vec::new();
+        self.reader.borrow_mut().read_to_end(&mut bytes)?;
+        decode_config(bytes)
+    }
+
+    fn format(&self) -> ConfigFormat {
+        self.format
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RouterConfig;
+    use std::collections::HashMap;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const SOURCE_CONFIG: &str = r#"
+policies:
+  - name: task_router
+    url: http://router:8000/v2/models/task_router/infer
+    llms:
+      - name: Brainstorming
+        api_base: https://integrate.api.nvidia.com
+        api_key: ${CONFIG_TEST_SOURCE_KEY:-test-key}
+        model: meta/llama-3.1-70b-instruct
+"#;
+
+    /// Configs kept in an embedder's key-value store.
+    struct MemorySource {
+        store: HashMap<&'static str, &'static str>,
+        key: &'static str,
+    }
+
+    impl ConfigSource for MemorySource {
+        fn read(&self) -> Result<String> {
+            self.store
+                .get(self.key)
+                .map(|content| content.to_string())
+                .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound).into())
+        }
+    }
+
+    #[test]
+    fn test_load_from_custom_source() {
+        let source = MemorySource {
+            store: HashMap::from([("router", SOURCE_CONFIG)]),
+            key: "router",
+        };
+        let config = RouterConfig::load_from_source(&source).unwrap();
+        assert_eq!(config.policies[0].llms[0].api_key, "test-key");
+
+        let missing = MemorySource {
+            key: "missing",
+            ..source
+        };
+        assert!(matches!(
+            RouterConfig::load_from_source(&missing),
+            Err(ConfigError::Io(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_url_source_reads_blocking() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/router.yaml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(SOURCE_CONFIG))
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/router.yaml", mock_server.uri());
+        let config = tokio::task::spawn_blocking(move || {
+            RouterConfig::load_from_source(&UrlSource::new(url))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.policies[0].name, "task_router");
+    }
+}
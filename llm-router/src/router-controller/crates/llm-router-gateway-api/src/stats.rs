@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stats
+use crate::config::RouterConfig;
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// At-a-glance numbers about a config, served from `/stats`.
+#[derive(Serialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigStats {
+    pub policy_count: usize,
+    /// Every LLM across all policies, including disabled ones.
+    pub llm_count: usize,
+    /// Distinct providers, counting an unset `provider` as the default.
+    pub provider_count: usize,
+    /// LLMs missing `input_cost_per_1k` or `output_cost_per_1k`.
+    pub llms_missing_cost: usize,
+}
+
+impl RouterConfig {
+    pub fn stats(&self) -> ConfigStats {
+        let llms = || self.policies.iter().flat_map(|policy| &policy.llms);
+        ConfigStats {
+            policy_count: self.policies.len(),
+            llm_count: llms().count(),
+            provider_count: llms()
+                .map(|llm| llm.provider_or_default())
+                .collect::<HashSet<_>>()
+                .len(),
+            llms_missing_cost: llms()
+                .filter(|llm| llm.input_cost_per_1k.is_none() || llm.output_cost_per_1k.is_none())
+                .count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Llm, Provider};
+
+    #[test]
+    fn test_stats_on_sample_config() {
+        std::env::set_var("NVIDIA_API_KEY", "test-key");
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../config.yaml");
+        let mut config = RouterConfig::load_config(path).unwrap();
+        let llm_count = config.policies.iter().map(|p| p.llms.len()).sum();
+        assert_eq!(
+            config.stats(),
+            ConfigStats {
+                policy_count: 2,
+                llm_count,
+                provider_count: 1,
+                llms_missing_cost: llm_count,
+            }
+        );
+
+        config.policies[0].llms[0] = Llm {
+            provider: Some(Provider::Anthropic),
+            input_cost_per_1k: Some(0.003),
+            output_cost_per_1k: Some(0.015),
+            ..config.policies[0].llms[0].clone()
+        };
+        let stats = config.stats();
+        assert_eq!(stats.provider_count, 2);
+        assert_eq!(stats.llms_missing_cost, llm_count - 1);
+    }
+}
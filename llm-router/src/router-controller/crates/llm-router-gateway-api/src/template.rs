@@ -0,0 +1,220 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Template
+use crate::config::Policy;
+use serde_json::Value;
+use tracing::debug;
+
+impl Policy {
+    /// Adds `system_prompt` to `messages` as a system message: first when the
+    /// request has none, otherwise right after its leading system messages so
+    /// the request's own instructions come first. Does nothing without a
+    /// `system_prompt`.
+    pub fn inject_system_prompt(&self, messages: &mut Vec<Value>) {
+        let Some(prompt) = &self.system_prompt else {
+            return;
+        };
+        let position = messages
+            .iter()
+            .take_while(|message| message["role"] == "system")
+            .count();
+        messages.insert(
+            position,
+            serde_json::json!({ "role": "system", "content": prompt }),
+        );
+    }
+
+    /// `incoming` with `request_template` merged in as defaults: fields the
+    /// request already sets win, and nested objects are merged key by key.
+    /// Template strings may reference request fields as `${field}` or
+    /// `${field.nested}`; a string that is a single placeholder takes the
+    /// referenced value as is. Placeholders for missing fields are left as is.
+    pub fn apply_request_template(&self, incoming: &Value) -> Value {
+        let mut outgoing = incoming.clone();
+        if let Some(template) = &self.request_template {
+            merge_defaults(&mut outgoing, render(template, incoming));
+        }
+        outgoing
+    }
+}
+
+fn render(template: &Value, incoming: &Value) -> Value {
+    match template {
+        Value::String(text) => render_string(text, incoming),
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|item| render(item, incoming)).collect())
+        }
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| (key.clone(), render(item, incoming)))
+                .collect(),
+        ),
+        scalar => scalar.clone(),
+    }
+}
+
+fn render_string(text: &str, incoming: &Value) -> Value {
+    if let Some(field) = text
+        .strip_prefix("${")
+        .and_then(|rest| rest.strip_suffix('}'))
+    {
+        if !field.contains('}') {
+            return match lookup(incoming, field) {
+                Some(value) => value.clone(),
+                None => {
+                    debug!(
+                        field,
+                        "Request template field is not set, keeping placeholder"
+                    );
+                    Value::String(text.to_string())
+                }
+            };
+        }
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+        let field = &rest[2..end];
+        match lookup(incoming, field) {
+            Some(Value::String(value)) => result.push_str(value),
+            Some(value) => result.push_str(&value.to_string()),
+            None => {
+                debug!(
+                    field,
+                    "Request template field is not set, keeping placeholder"
+                );
+                result.push_str(&rest[..=end]);
+            }
+        }
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    Value::String(result)
+}
+
+/// Follows a dotted path of object keys and array indices.
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, segment| match current {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+fn merge_defaults(target: &mut Value, defaults: Value) {
+    if let (Value::Object(target), Value::Object(defaults)) = (target, defaults) {
+        for (key, default) in defaults {
+            match target.get_mut(&key) {
+                Some(existing) => merge_defaults(existing, default),
+                None => {
+                    target.insert(key, default);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::test_policy;
+    use serde_json::json;
+
+    fn policy(template: Value) -> Policy {
+        Policy {
+            request_template: Some(template),
+            ..test_policy("templated", Vec::new())
+        }
+    }
+
+    #[test]
+    fn test_template_fills_missing_temperature() {
+        let policy = policy(json!({"temperature": 0.2, "max_tokens": 256}));
+        let incoming = json!({"model": "", "max_tokens": 1024, "messages": []});
+        assert_eq!(
+            policy.apply_request_template(&incoming),
+            json!({"model": "", "max_tokens": 1024, "messages": [], "temperature": 0.2})
+        );
+    }
+
+    #[test]
+    fn test_template_placeholders() {
+        let policy = policy(json!({
+            "user": "${metadata.user}",
+            "metadata": {"source": "gateway for ${metadata.user}", "trace": "${trace_id}"},
+            "first_role": "${messages.0.role}",
+        }));
+        let incoming = json!({
+            "messages": [{"role": "system", "content": "Be brief"}],
+            "metadata": {"user": "alice"},
+        });
+        let outgoing = policy.apply_request_template(&incoming);
+        assert_eq!(outgoing["user"], "alice");
+        assert_eq!(outgoing["first_role"], "system");
+        assert_eq!(outgoing["metadata"]["user"], "alice");
+        assert_eq!(outgoing["metadata"]["source"], "gateway for alice");
+        assert_eq!(outgoing["metadata"]["trace"], "${trace_id}");
+    }
+
+    #[test]
+    fn test_inject_system_prompt() {
+        let prompted = Policy {
+            system_prompt: Some("Answer in English.".to_string()),
+            ..policy(json!({}))
+        };
+        let injected = json!({ "role": "system", "content": "Answer in English." });
+
+        let mut messages = vec![json!({ "role": "user", "content": "Hello" })];
+        prompted.inject_system_prompt(&mut messages);
+        assert_eq!(
+            messages,
+            vec![
+                injected.clone(),
+                json!({ "role": "user", "content": "Hello" })
+            ]
+        );
+
+        let mut messages = vec![
+            json!({ "role": "system", "content": "Be brief." }),
+            json!({ "role": "user", "content": "Hello" }),
+        ];
+        prompted.inject_system_prompt(&mut messages);
+        assert_eq!(
+            messages,
+            vec![
+                json!({ "role": "system", "content": "Be brief." }),
+                injected,
+                json!({ "role": "user", "content": "Hello" }),
+            ]
+        );
+
+        let mut messages = Vec::new();
+        prompted.inject_system_prompt(&mut messages);
+        assert_eq!(messages.len(), 1);
+
+        let unprompted = policy(json!({}));
+        let mut messages = vec![json!({ "role": "user", "content": "Hello" })];
+        unprompted.inject_system_prompt(&mut messages);
+        assert_eq!(messages.len(), 1);
+    }
+}
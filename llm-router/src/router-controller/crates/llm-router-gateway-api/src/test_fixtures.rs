@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test fixtures shared by the unit tests of every module
+use crate::config::{Llm, Policy, RouterConfig};
+
+pub(crate) const TRITON_URL: &str =
+    "http://router-server:8000/v2/models/task_router_ensemble/infer";
+
+pub(crate) fn test_llm(name: &str, weight: Option<u32>) -> Llm {
+    Llm {
+        name: name.to_string(),
+        api_base: "https://integrate.api.nvidia.com".to_string(),
+        api_key: "test-key".to_string(),
+        model: "meta/llama-3.1-8b-instruct".to_string(),
+        weight,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn test_policy(name: &str, llms: Vec<Llm>) -> Policy {
+    Policy {
+        name: name.to_string(),
+        url: TRITON_URL.to_string(),
+        llms,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn test_config(policies: Vec<Policy>) -> RouterConfig {
+    RouterConfig {
+        policies,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,298 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Watch
+use crate::config::{read_config_file, LoadOptions, Result, RouterConfig};
+use crate::diff::ConfigDiff;
+use crate::error::ConfigError;
+use arc_swap::ArcSwap;
+#[cfg(unix)]
+use log::{error, info};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// How often `RouterConfig::watch` checks the config file for changes.
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Keeps a config watcher running. Dropping the handle stops the background
+/// thread and waits for it to exit.
+pub struct WatchHandle {
+    stop: Option<Sender<()>>,
+    errors: Receiver<ConfigError>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl WatchHandle {
+    /// Errors from reloads that were rejected. The shared config keeps serving
+    /// the last good version whenever one is reported here.
+    pub fn errors(&self) -> &Receiver<ConfigError> {
+        &self.errors
+    }
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        // Disconnecting the stop channel wakes the watcher immediately
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl RouterConfig {
+    /// Loads the config at `path` and reloads it whenever the file contents
+    /// change. A reloaded config is only swapped in if it loads and validates;
+    /// otherwise the error is sent to `WatchHandle::errors`.
+    pub fn watch(path: &str) -> Result<(Arc<ArcSwap<RouterConfig>>, WatchHandle)> {
+        Self::watch_with_interval(path, DEFAULT_WATCH_INTERVAL)
+    }
+
+    pub fn watch_with_interval(
+        path: &str,
+        interval: Duration,
+    ) -> Result<(Arc<ArcSwap<RouterConfig>>, WatchHandle)> {
+        Self::watch_with_options(path, interval, LoadOptions::default())
+    }
+
+    /// `watch_with_interval` loading the initial config and every reload with
+    /// `options`, so a config that started leniently also reloads leniently.
+    pub fn watch_with_options(
+        path: &str,
+        interval: Duration,
+        options: LoadOptions,
+    ) -> Result<(Arc<ArcSwap<RouterConfig>>, WatchHandle)> {
+        let mut last_content = read_config_file(path)?;
+        let config = Arc::new(ArcSwap::from_pointee(
+            RouterConfig::load_config_with_options(path, options)?,
+        ));
+
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let (error_tx, error_rx) = mpsc::channel();
+        let shared = Arc::clone(&config);
+        let path = path.to_string();
+
+        let thread = thread::spawn(move || loop {
+            match stop_rx.recv_timeout(interval) {
+                Err(RecvTimeoutError::Timeout) => {}
+                _ => return,
+            }
+
+            let content = match read_config_file(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    let _ = error_tx.send(e);
+                    continue;
+                }
+            };
+            if content == last_content {
+                continue;
+            }
+            last_content = content;
+
+            if let Err(e) = reload(&path, &shared, options) {
+                let _ = error_tx.send(e);
+            }
+        });
+
+        Ok((
+            config,
+            WatchHandle {
+                stop: Some(stop_tx),
+                errors: error_rx,
+                thread: Some(thread),
+            },
+        ))
+    }
+
+    /// Loads the config at `path` and reloads it each time the process receives
+    /// SIGHUP, keeping the previous config if the new one fails to load or
+    /// validate. Must be called from within a Tokio runtime.
+    ///
+    /// Install at most one handler per process: every handler reacts to the same
+    /// signal, and handlers for different paths would race to swap their own
+    /// config in.
+    #[cfg(unix)]
+    pub fn reload_on_sighup(path: String) -> Result<Arc<ArcSwap<RouterConfig>>> {
+        Self::reload_on_sighup_with_options(path, LoadOptions::default())
+    }
+
+    /// `reload_on_sighup` loading the initial config and every reload with
+    /// `options`.
+    #[cfg(unix)]
+    pub fn reload_on_sighup_with_options(
+        path: String,
+        options: LoadOptions,
+    ) -> Result<Arc<ArcSwap<RouterConfig>>> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let config = Arc::new(ArcSwap::from_pointee(
+            RouterConfig::load_config_with_options(&path, options)?,
+        ));
+        let mut hangups = signal(SignalKind::hangup())?;
+        let shared = Arc::clone(&config);
+        tokio::spawn(async move {
+            while hangups.recv().await.is_some() {
+                match reload(&path, &shared, options) {
+                    Ok(Some(diff)) if diff.secrets_only() => {
+                        info!("Refreshed credentials from {}", path)
+                    }
+                    Ok(Some(_)) => info!("Reloaded configuration from {}", path),
+                    Ok(None) => info!("Configuration in {} is unchanged", path),
+                    Err(e) => error!("Failed to reload configuration, keeping previous: {}", e),
+                }
+            }
+        });
+        Ok(config)
+    }
+}
+
+/// Swaps the config at `path`, loaded with `options`, into `shared` if it
+/// loads, validates and differs semantically from the current one. Returns
+/// what changed if it was swapped in.
+fn reload(
+    path: &str,
+    shared: &ArcSwap<RouterConfig>,
+    options: LoadOptions,
+) -> Result<Option<ConfigDiff>> {
+    let config = RouterConfig::load_config_with_options(path, options)?;
+    let current = shared.load();
+    if config.semantically_eq(&current) {
+        return Ok(None);
+    }
+    let diff = current.diff(&config);
+    shared.store(Arc::new(config));
+    Ok(Some(diff))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOOD_CONFIG: &str = r#"
+policies:
+  - name: "task_router"
+    url: http://router-server:8000/v2/models/task_router_ensemble/infer
+    llms:
+      - name: Brainstorming
+        api_base: https://integrate.api.nvidia.com
+        api_key: test-key
+        model: meta/llama-3.1-70b-instruct
+"#;
+
+    #[test]
+    fn test_watch_keeps_last_good_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, GOOD_CONFIG).unwrap();
+
+        let (config, handle) =
+            RouterConfig::watch_with_interval(path.to_str().unwrap(), Duration::from_millis(20))
+                .unwrap();
+        assert_eq!(config.load().policies[0].llms[0].name, "Brainstorming");
+
+        // An invalid config is reported and the previous one keeps serving
+        std::fs::write(
+            &path,
+            GOOD_CONFIG.replace(
+                "api_base: https://integrate.api.nvidia.com",
+                "api_base: \"\"",
+            ),
+        )
+        .unwrap();
+        let error = handle
+            .errors()
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap();
+        assert!(matches!(error, ConfigError::MissingLlmField { .. }));
+        assert_eq!(
+            config.load().policies[0].llms[0].api_base,
+            "https://integrate.api.nvidia.com"
+        );
+
+        // A subsequent valid edit is picked up
+        std::fs::write(&path, GOOD_CONFIG.replace("Brainstorming", "Chatbot")).unwrap();
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while config.load().policies[0].llms[0].name != "Chatbot" {
+            assert!(std::time::Instant::now() < deadline, "reload not observed");
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        drop(handle);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_sighup_reload_keeps_previous_on_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, GOOD_CONFIG).unwrap();
+        let path = path.to_str().unwrap();
+        let shared = ArcSwap::from_pointee(RouterConfig::load_config(path).unwrap());
+
+        std::fs::write(path, GOOD_CONFIG.replace("test-key", "\"\"")).unwrap();
+        assert!(matches!(
+            reload(path, &shared, LoadOptions::default()),
+            Err(ConfigError::AmbiguousApiKey { .. })
+        ));
+        assert_eq!(shared.load().policies[0].llms[0].api_key, "test-key");
+
+        std::fs::write(path, GOOD_CONFIG.replace("test-key", "rotated-key")).unwrap();
+        assert!(reload(path, &shared, LoadOptions::default())
+            .unwrap()
+            .unwrap()
+            .secrets_only());
+
+        std::fs::write(path, GOOD_CONFIG.replace("Brainstorming", "Chatbot")).unwrap();
+        assert!(!reload(path, &shared, LoadOptions::default())
+            .unwrap()
+            .unwrap()
+            .secrets_only());
+        assert_eq!(shared.load().policies[0].llms[0].name, "Chatbot");
+    }
+
+    #[test]
+    fn test_lenient_reload_keeps_unresolved_placeholder() {
+        std::env::remove_var("CONFIG_TEST_WATCH_UNSET");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        let unresolved = GOOD_CONFIG.replace("test-key", "${CONFIG_TEST_WATCH_UNSET}");
+        std::fs::write(&path, &unresolved).unwrap();
+        let path = path.to_str().unwrap();
+        let options = LoadOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let shared =
+            ArcSwap::from_pointee(RouterConfig::load_config_with_options(path, options).unwrap());
+
+        std::fs::write(path, unresolved.replace("Brainstorming", "Chatbot")).unwrap();
+        assert!(matches!(
+            reload(path, &shared, LoadOptions::default()),
+            Err(ConfigError::UnresolvedEnvVars { .. })
+        ));
+        assert!(reload(path, &shared, options).unwrap().is_some());
+        assert_eq!(shared.load().policies[0].llms[0].name, "Chatbot");
+
+        // Read like any config file, so bad encoding is reported as such
+        std::fs::write(path, b"policies: \xff\n").unwrap();
+        assert!(matches!(
+            reload(path, &shared, options),
+            Err(ConfigError::InvalidEncoding { .. })
+        ));
+    }
+}
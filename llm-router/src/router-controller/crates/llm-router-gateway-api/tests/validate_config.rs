@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::process::Command;
+
+const GOOD_CONFIG: &str = r#"
+policies:
+  - name: "task_router"
+    url: http://router-server:8000/v2/models/task_router_ensemble/infer
+    llms:
+      - name: Brainstorming
+        api_base: https://integrate.api.nvidia.com
+        api_key: live-secret-key
+        model: meta/llama-3.1-70b-instruct
+"#;
+
+fn write_config(content: &str) -> tempfile::NamedTempFile {
+    let mut file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file
+}
+
+fn validate(file: &tempfile::NamedTempFile, extra_args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_validate-config"))
+        .arg(file.path())
+        .args(extra_args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_good_config_passes_with_redacted_summary() {
+    let file = write_config(GOOD_CONFIG);
+    let output = validate(&file, &[]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("policy 'task_router'"));
+    assert!(stdout.contains("llm 'Brainstorming'"));
+    assert!(!stdout.contains("live-secret-key"));
+}
+
+#[test]
+fn test_bad_config_fails_with_json_errors() {
+    let file =
+        write_config(&GOOD_CONFIG.replace("model: meta/llama-3.1-70b-instruct", "model: \"\""));
+    let output = validate(&file, &["--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["valid"], false);
+    assert!(report["config"].is_null());
+    assert!(report["errors"][0].as_str().unwrap().contains("model"));
+}
+
+#[test]
+fn test_unresolved_env_var_is_a_warning_unless_strict() {
+    let file =
+        write_config(&GOOD_CONFIG.replace("live-secret-key", "${VALIDATE_CONFIG_TEST_UNSET_KEY}"));
+    let output = validate(&file, &["--format", "json"]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["valid"], true);
+    assert!(report["warnings"][0]
+        .as_str()
+        .unwrap()
+        .contains("VALIDATE_CONFIG_TEST_UNSET_KEY"));
+    assert_eq!(
+        report["config"]["policies"][0]["llms"][0]["api_key"],
+        "[REDACTED]"
+    );
+
+    let output = validate(&file, &["--strict"]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_dash_reads_config_from_stdin() {
+    use std::process::Stdio;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_validate-config"))
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(GOOD_CONFIG.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("- is valid: 1 policies"));
+}